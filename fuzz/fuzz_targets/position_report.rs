@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sms_types::gnss::PositionReport;
+
+fuzz_target!(|line: &str| {
+    let fields: Vec<&str> = line.split(',').collect();
+    if let Ok(report) = PositionReport::from_fields(&fields) {
+        let _ = report.utc_time_millis();
+    }
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sms_types::sms::SmsMultipartHeader;
+
+fuzz_target!(|data: Vec<u8>| {
+    let _ = SmsMultipartHeader::try_from(data);
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sms_types::events::WebhookPayload;
+
+// Structurally valid payloads must survive a JSON round trip.
+// Non-finite floats serialize as null and can't be read back, so those errors are expected.
+fuzz_target!(|payload: WebhookPayload| {
+    let json = serde_json::to_string(&payload).expect("serialize payload");
+    if let Err(e) = serde_json::from_str::<WebhookPayload>(&json) {
+        assert!(
+            e.to_string().starts_with("invalid type: null"),
+            "deserialize payload: {e}\n{json}"
+        );
+    }
+});
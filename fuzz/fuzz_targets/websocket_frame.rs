@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sms_types::websocket::{WebsocketFrame, WebsocketMessage};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = serde_json::from_slice::<WebsocketFrame<WebsocketMessage>>(data);
+});
@@ -0,0 +1,105 @@
+/* C declarations for the sms-types `ffi` feature, see src/ffi.rs. */
+
+#ifndef SMS_TYPES_H
+#define SMS_TYPES_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Event kinds, as stored in CEvent.kind. */
+#define SMS_EVENT_INCOMING 0
+#define SMS_EVENT_OUTGOING 1
+#define SMS_EVENT_DELIVERY 2
+#define SMS_EVENT_MODEM_STATUS_UPDATE 3
+#define SMS_EVENT_GNSS_POSITION_REPORT 4
+#define SMS_EVENT_WEBSOCKET_CONNECTION_UPDATE 5
+#define SMS_EVENT_GNSS_POWER_STATE_UPDATE 6
+#define SMS_EVENT_GNSS_FIX_ACQUIRED 7
+#define SMS_EVENT_USSD_RESPONSE 8
+#define SMS_EVENT_INCOMING_CALL 9
+#define SMS_EVENT_SIM_STATUS_CHANGE 10
+#define SMS_EVENT_SIGNAL_STRENGTH_UPDATE 11
+#define SMS_EVENT_STORAGE_FULL 12
+#define SMS_EVENT_WEBHOOK_DELIVERY_FAILED 13
+#define SMS_EVENT_DIGEST 14
+#define SMS_EVENT_CUSTOM 15
+#define SMS_EVENT_BATTERY_LOW 16
+#define SMS_EVENT_GEOFENCE_TRANSITION 17
+#define SMS_EVENT_UNKNOWN 255
+
+/* Modem statuses, as stored in CEvent.modem_previous and CEvent.modem_current. */
+#define SMS_MODEM_STARTUP 0
+#define SMS_MODEM_ONLINE 1
+#define SMS_MODEM_SHUTTING_DOWN 2
+#define SMS_MODEM_OFFLINE 3
+#define SMS_MODEM_OTHER 255
+
+typedef enum SmsResult {
+    SMS_RESULT_OK = 0,
+    SMS_RESULT_NULL_POINTER = 1,
+    SMS_RESULT_INVALID_UTF8 = 2,
+    SMS_RESULT_INVALID_JSON = 3,
+    SMS_RESULT_SERIALIZE_FAILED = 4,
+} SmsResult;
+
+typedef struct CSmsMessage {
+    int64_t message_id;
+    bool has_message_id;
+    char *phone_number;
+    char *message_content;
+    uint8_t message_reference;
+    bool has_message_reference;
+    bool is_outgoing;
+    uint32_t created_at;
+    bool has_created_at;
+    uint32_t completed_at;
+    bool has_completed_at;
+    uint8_t status;
+    bool has_status;
+} CSmsMessage;
+
+typedef struct CDeliveryReport {
+    int64_t message_id;
+    char *phone_number;
+    uint8_t reference_id;
+    uint8_t status;
+} CDeliveryReport;
+
+typedef struct CEvent {
+    uint8_t kind;
+    CSmsMessage message;
+    uint64_t origin_client_id;
+    bool has_origin_client_id;
+    CDeliveryReport delivery_report;
+    uint8_t modem_previous;
+    uint8_t modem_current;
+    char *json;
+} CEvent;
+
+/* Parse a message from JSON, release it with sms_message_free. */
+SmsResult sms_message_from_json(const char *json, CSmsMessage *out);
+
+/* Serialize a message to JSON, release it with sms_string_free. Returns NULL if invalid. */
+char *sms_message_to_json(const CSmsMessage *message);
+
+/* Release the strings of a message populated by sms_message_from_json. */
+void sms_message_free(CSmsMessage *message);
+
+/* Parse an event from JSON, release it with sms_event_free. */
+SmsResult sms_event_from_json(const char *json, CEvent *out);
+
+/* Release the strings of an event populated by sms_event_from_json. */
+void sms_event_free(CEvent *event);
+
+/* Release a string returned by this library. */
+void sms_string_free(char *value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SMS_TYPES_H */
@@ -0,0 +1,251 @@
+//! Avro schemas and datum encoding, for landing messages and events in analytics pipelines.
+//! Datums are encoded without a header, so they can be framed for a schema registry,
+//! eg: with the Confluent wire format of a magic byte and schema id.
+//!
+//! Envelopes carry the event as JSON in `data`, with the kind, phone number and any
+//! message extracted as columns so they can be queried without parsing it.
+
+use crate::events::{EventEnvelope, EventKind};
+use crate::sms::SmsMessage;
+use apache_avro::Schema;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+/// The Avro schema of an `SmsMessage` record.
+pub const SMS_MESSAGE_SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "SmsMessage",
+    "namespace": "sms_types",
+    "fields": [
+        {"name": "message_id", "type": ["null", "long"], "default": null},
+        {"name": "phone_number", "type": "string"},
+        {"name": "message_content", "type": "string"},
+        {"name": "message_reference", "type": ["null", "int"], "default": null},
+        {"name": "is_outgoing", "type": "boolean"},
+        {"name": "created_at", "type": ["null", "long"], "default": null, "doc": "Unix timestamp in seconds."},
+        {"name": "completed_at", "type": ["null", "long"], "default": null, "doc": "Unix timestamp in seconds."},
+        {"name": "status", "type": ["null", "int"], "default": null}
+    ]
+}"#;
+
+static SMS_MESSAGE: LazyLock<Schema> =
+    LazyLock::new(|| Schema::parse_str(SMS_MESSAGE_SCHEMA).expect("valid SmsMessage schema"));
+
+static EVENT_ENVELOPE: LazyLock<Schema> = LazyLock::new(|| {
+    let schema = format!(
+        r#"{{
+            "type": "record",
+            "name": "EventEnvelope",
+            "namespace": "sms_types",
+            "fields": [
+                {{"name": "schema_version", "type": "int"}},
+                {{"name": "event_id", "type": {{"type": "string", "logicalType": "uuid"}}}},
+                {{"name": "sequence", "type": "long"}},
+                {{"name": "emitted_at", "type": {{"type": "long", "logicalType": "timestamp-millis"}}}},
+                {{"name": "kind", "type": "string"}},
+                {{"name": "phone_number", "type": ["null", "string"], "default": null}},
+                {{"name": "message", "type": ["null", {SMS_MESSAGE_SCHEMA}], "default": null}},
+                {{"name": "data", "type": "string", "doc": "The event as JSON."}}
+            ]
+        }}"#
+    );
+    Schema::parse_str(&schema).expect("valid EventEnvelope schema")
+});
+
+/// Get the Avro schema of an `SmsMessage` record.
+#[must_use]
+pub fn sms_message_schema() -> &'static Schema {
+    &SMS_MESSAGE
+}
+
+/// Get the Avro schema of an `EventEnvelope` record.
+#[must_use]
+pub fn event_envelope_schema() -> &'static Schema {
+    &EVENT_ENVELOPE
+}
+
+/// An error encoding or decoding an Avro datum.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AvroError {
+    /// The datum could not be written or read.
+    Avro(apache_avro::Error),
+
+    /// The event data could not be converted to or from JSON.
+    Json(serde_json::Error),
+
+    /// A field value is invalid or does not fit its Avro type, eg: a sequence above `i64::MAX`.
+    InvalidField(&'static str),
+}
+impl std::fmt::Display for AvroError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AvroError::Avro(e) => write!(f, "Avro error: {e}"),
+            AvroError::Json(e) => write!(f, "Invalid event data: {e}"),
+            AvroError::InvalidField(field) => write!(f, "Invalid value for Avro field {field}"),
+        }
+    }
+}
+impl std::error::Error for AvroError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AvroError::Avro(e) => Some(e),
+            AvroError::Json(e) => Some(e),
+            AvroError::InvalidField(_) => None,
+        }
+    }
+}
+impl From<apache_avro::Error> for AvroError {
+    fn from(value: apache_avro::Error) -> Self {
+        AvroError::Avro(value)
+    }
+}
+impl From<serde_json::Error> for AvroError {
+    fn from(value: serde_json::Error) -> Self {
+        AvroError::Json(value)
+    }
+}
+
+/// The Avro record of an `SmsMessage`, with fixed field names and signed integers.
+#[derive(Serialize, Deserialize)]
+struct SmsMessageRecord {
+    message_id: Option<i64>,
+    phone_number: String,
+    message_content: String,
+    message_reference: Option<i32>,
+    is_outgoing: bool,
+    created_at: Option<i64>,
+    completed_at: Option<i64>,
+    status: Option<i32>,
+}
+impl From<&SmsMessage> for SmsMessageRecord {
+    fn from(value: &SmsMessage) -> Self {
+        Self {
+            message_id: value.message_id,
+            phone_number: value.phone_number.to_string(),
+            message_content: value.message_content.to_string(),
+            message_reference: value.message_reference.map(i32::from),
+            is_outgoing: value.is_outgoing,
+            created_at: value.created_at.map(i64::from),
+            completed_at: value.completed_at.map(i64::from),
+            status: value.status.map(i32::from),
+        }
+    }
+}
+impl TryFrom<SmsMessageRecord> for SmsMessage {
+    type Error = AvroError;
+
+    fn try_from(value: SmsMessageRecord) -> Result<Self, Self::Error> {
+        Ok(Self {
+            message_id: value.message_id,
+            phone_number: value.phone_number.into(),
+            message_content: value.message_content.into(),
+            message_reference: narrow(value.message_reference, "message_reference")?,
+            is_outgoing: value.is_outgoing,
+            created_at: narrow(value.created_at, "created_at")?,
+            completed_at: narrow(value.completed_at, "completed_at")?,
+            status: narrow(value.status, "status")?,
+        })
+    }
+}
+
+/// The Avro record of an `EventEnvelope`.
+#[derive(Serialize, Deserialize)]
+struct EventEnvelopeRecord {
+    schema_version: i32,
+    event_id: String,
+    sequence: i64,
+    emitted_at: i64,
+    kind: String,
+    phone_number: Option<String>,
+    message: Option<SmsMessageRecord>,
+    data: String,
+}
+impl TryFrom<&EventEnvelope> for EventEnvelopeRecord {
+    type Error = AvroError;
+
+    fn try_from(value: &EventEnvelope) -> Result<Self, Self::Error> {
+        use crate::events::Event;
+
+        let message = match &value.event {
+            Event::IncomingMessage(message) | Event::OutgoingMessage { message, .. } => {
+                Some(SmsMessageRecord::from(message))
+            }
+            _ => None,
+        };
+        Ok(Self {
+            schema_version: i32::from(value.schema_version),
+            event_id: value.event_id.to_string(),
+            sequence: i64::try_from(value.sequence)
+                .map_err(|_| AvroError::InvalidField("sequence"))?,
+            emitted_at: i64::try_from(value.emitted_at)
+                .map_err(|_| AvroError::InvalidField("emitted_at"))?,
+            kind: EventKind::from(&value.event).as_str().to_string(),
+            phone_number: value.event.phone_number().map(ToString::to_string),
+            message,
+            data: serde_json::to_string(&value.event)?,
+        })
+    }
+}
+impl TryFrom<EventEnvelopeRecord> for EventEnvelope {
+    type Error = AvroError;
+
+    fn try_from(value: EventEnvelopeRecord) -> Result<Self, Self::Error> {
+        Ok(Self {
+            schema_version: u16::try_from(value.schema_version)
+                .map_err(|_| AvroError::InvalidField("schema_version"))?,
+            event_id: value
+                .event_id
+                .parse()
+                .map_err(|_| AvroError::InvalidField("event_id"))?,
+            sequence: u64::try_from(value.sequence)
+                .map_err(|_| AvroError::InvalidField("sequence"))?,
+            emitted_at: u64::try_from(value.emitted_at)
+                .map_err(|_| AvroError::InvalidField("emitted_at"))?,
+            event: serde_json::from_str(&value.data)?,
+        })
+    }
+}
+
+/// Narrow an optional Avro integer into a smaller unsigned type.
+fn narrow<T: TryFrom<U>, U>(value: Option<U>, field: &'static str) -> Result<Option<T>, AvroError> {
+    value
+        .map(T::try_from)
+        .transpose()
+        .map_err(|_| AvroError::InvalidField(field))
+}
+
+/// Encode a record as an Avro datum.
+fn encode<T: Serialize>(schema: &Schema, record: &T) -> Result<Vec<u8>, AvroError> {
+    let writer = apache_avro::writer::datum::GenericDatumWriter::builder(schema).build()?;
+    Ok(writer.write_value_to_vec(apache_avro::to_value(record)?)?)
+}
+
+/// Decode a record from an Avro datum.
+fn decode<T: for<'de> Deserialize<'de>>(schema: &Schema, mut bytes: &[u8]) -> Result<T, AvroError> {
+    let reader = apache_avro::reader::datum::GenericDatumReader::builder(schema).build()?;
+    Ok(apache_avro::from_value(&reader.read_value(&mut bytes)?)?)
+}
+
+/// Encode a message as an Avro datum.
+pub(crate) fn encode_sms_message(message: &SmsMessage) -> Result<Vec<u8>, AvroError> {
+    encode(sms_message_schema(), &SmsMessageRecord::from(message))
+}
+
+/// Decode a message from an Avro datum.
+pub(crate) fn decode_sms_message(bytes: &[u8]) -> Result<SmsMessage, AvroError> {
+    decode::<SmsMessageRecord>(sms_message_schema(), bytes)?.try_into()
+}
+
+/// Encode an envelope as an Avro datum.
+pub(crate) fn encode_event_envelope(envelope: &EventEnvelope) -> Result<Vec<u8>, AvroError> {
+    encode(
+        event_envelope_schema(),
+        &EventEnvelopeRecord::try_from(envelope)?,
+    )
+}
+
+/// Decode an envelope from an Avro datum.
+pub(crate) fn decode_event_envelope(bytes: &[u8]) -> Result<EventEnvelope, AvroError> {
+    decode::<EventEnvelopeRecord>(event_envelope_schema(), bytes)?.try_into()
+}
@@ -0,0 +1,103 @@
+//! Voice call notification types.
+
+use serde::{Deserialize, Serialize};
+
+/// Whether the caller's number was presented, as reported in `+CLIP`.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
+pub enum CallerIdValidity {
+    /// The caller's number is valid.
+    Valid,
+
+    /// The caller withheld their number.
+    Withheld,
+
+    /// The number is unavailable, eg: due to interworking problems.
+    Unavailable,
+}
+impl core::fmt::Display for CallerIdValidity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            CallerIdValidity::Valid => "Valid",
+            CallerIdValidity::Withheld => "Withheld",
+            CallerIdValidity::Unavailable => "Unavailable",
+        })
+    }
+}
+impl TryFrom<u8> for CallerIdValidity {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(CallerIdValidity::Valid),
+            1 => Ok(CallerIdValidity::Withheld),
+            2 => Ok(CallerIdValidity::Unavailable),
+            _ => Err(format!("Invalid caller ID validity: {value}")),
+        }
+    }
+}
+
+/// Caller identification for an incoming call.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct CallerId {
+    /// The caller's phone number, if it was presented.
+    pub phone_number: Option<String>,
+
+    /// The type of address octet (eg: 145 for international numbers).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_type: Option<u8>,
+
+    /// The caller's name from the phonebook or network, if available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Whether the number was presented.
+    pub validity: CallerIdValidity,
+}
+impl CallerId {
+    /// Returns true if the caller's number is known.
+    #[must_use]
+    pub fn is_known(&self) -> bool {
+        self.validity == CallerIdValidity::Valid && self.phone_number.is_some()
+    }
+}
+
+/// An incoming voice call.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct IncomingCall {
+    /// The caller identification.
+    pub caller: CallerId,
+
+    /// Unix timestamp when the call started ringing.
+    pub received_at: u32,
+}
+impl IncomingCall {
+    /// Get when the call started ringing.
+    #[must_use]
+    pub fn received_timestamp(&self) -> crate::timestamp::Timestamp {
+        crate::timestamp::Timestamp::from_unix_seconds(u64::from(self.received_at))
+    }
+}
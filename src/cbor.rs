@@ -0,0 +1,190 @@
+//! CBOR encoding with deterministic map ordering, for receivers that compare or hash payloads.
+//! Map entries are sorted by their encoded key bytes, as in RFC 8949 core deterministic encoding.
+
+use ciborium::Value;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// An error encoding a value as CBOR.
+pub type EncodeError = ciborium::ser::Error<std::io::Error>;
+
+/// An error decoding a value from CBOR.
+pub type DecodeError = ciborium::de::Error<std::io::Error>;
+
+/// Encode a value as deterministic CBOR, the same value always produces the same bytes.
+///
+/// # Errors
+/// Returns an error if the value cannot be serialized.
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, EncodeError> {
+    let mut value = Value::serialized(value).map_err(|e| match e {
+        ciborium::value::Error::Custom(message) => EncodeError::Value(message),
+    })?;
+    canonicalize(&mut value)?;
+
+    let mut bytes = Vec::new();
+    ciborium::into_writer(&value, &mut bytes)?;
+    Ok(bytes)
+}
+
+/// Decode a value from CBOR.
+///
+/// # Errors
+/// Returns an error if the bytes are not a valid encoding of the value.
+pub fn from_slice<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, DecodeError> {
+    ciborium::from_reader(bytes)
+}
+
+/// Recursively sort all map entries by their encoded key bytes.
+fn canonicalize(value: &mut Value) -> Result<(), EncodeError> {
+    match value {
+        Value::Map(entries) => {
+            let mut keyed = Vec::with_capacity(entries.len());
+            for (mut key, mut value) in entries.drain(..) {
+                canonicalize(&mut key)?;
+                canonicalize(&mut value)?;
+
+                let mut encoded = Vec::new();
+                ciborium::into_writer(&key, &mut encoded)?;
+                keyed.push((encoded, key, value));
+            }
+            keyed.sort_by(|a, b| a.0.cmp(&b.0));
+            entries.extend(keyed.into_iter().map(|(_, key, value)| (key, value)));
+        }
+        Value::Array(items) => {
+            for item in items {
+                canonicalize(item)?;
+            }
+        }
+        Value::Tag(_, inner) => canonicalize(inner)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "cbor"))]
+mod tests {
+    use super::*;
+    use crate::events::{Event, Uuid};
+    use crate::modem::ModemStatusUpdateState;
+    use crate::sms::SmsMessage;
+
+    fn message() -> SmsMessage {
+        SmsMessage {
+            message_id: Some(42),
+            phone_number: "+447700900123".into(),
+            message_content: "Hello".into(),
+            message_reference: Some(7),
+            is_outgoing: true,
+            created_at: Some(1_700_000_000),
+            completed_at: None,
+            status: Some(0),
+        }
+    }
+
+    fn map(entries: &[(&str, i64)]) -> Value {
+        Value::Map(
+            entries
+                .iter()
+                .map(|(key, value)| (Value::from(*key), Value::from(*value)))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn insertion_order_does_not_change_encoding() {
+        let forward = map(&[("a", 1), ("b", 2), ("aa", 3)]);
+        let reverse = map(&[("aa", 3), ("b", 2), ("a", 1)]);
+        assert_eq!(to_vec(&forward).unwrap(), to_vec(&reverse).unwrap());
+
+        let nested_forward = Value::Array(vec![forward]);
+        let nested_reverse = Value::Array(vec![reverse]);
+        assert_eq!(
+            to_vec(&nested_forward).unwrap(),
+            to_vec(&nested_reverse).unwrap()
+        );
+    }
+
+    #[test]
+    fn keys_are_sorted_by_encoded_bytes() {
+        let bytes = to_vec(&map(&[("aa", 3), ("b", 2), ("a", 1)])).unwrap();
+        let Value::Map(entries) = from_slice::<Value>(&bytes).unwrap() else {
+            panic!("expected a map");
+        };
+        let keys: Vec<_> = entries
+            .iter()
+            .map(|(key, _)| key.as_text().unwrap())
+            .collect();
+
+        // Shorter keys sort first, as their length prefix is encoded first.
+        assert_eq!(keys, ["a", "b", "aa"]);
+    }
+
+    #[test]
+    fn message_round_trips() {
+        let message = message();
+        let decoded: SmsMessage = from_slice(&to_vec(&message).unwrap()).unwrap();
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn event_round_trips() {
+        let events = [
+            Event::IncomingMessage(message()),
+            Event::OutgoingMessage {
+                message: message(),
+                origin_client_id: Some(3),
+            },
+            Event::Custom {
+                name: "door_opened".into(),
+                data: serde_json::json!({ "door": "front", "open": true }),
+            },
+        ];
+        for event in events {
+            let decoded: Event = from_slice(&to_vec(&event).unwrap()).unwrap();
+            assert_eq!(decoded, event);
+        }
+    }
+
+    #[test]
+    fn event_with_data_before_type_round_trips() {
+        let events = [
+            Event::ModemStatusUpdate {
+                previous: ModemStatusUpdateState::Online,
+                current: ModemStatusUpdateState::Other("Flashing".into()),
+            },
+            Event::WebhookDeliveryFailed {
+                webhook_id: "primary".into(),
+                original_event_id: Uuid::from_u128(0x6f1e_353b_2a9e_4c1d_8b5f_0a1b_2c3d_4e5f),
+                attempts: 5,
+                final_error: "timed out".into(),
+            },
+            Event::Unknown {
+                kind: "sms_failed".into(),
+                data: serde_json::json!({ "reason": "no signal", "retries": [1, 2] }),
+            },
+        ];
+        for event in events {
+            let bytes = to_vec(&event).unwrap();
+            let Value::Map(entries) = from_slice::<Value>(&bytes).unwrap() else {
+                panic!("expected a map");
+            };
+            assert_eq!(entries[0].0.as_text(), Some("data"));
+
+            let decoded: Event = from_slice(&bytes).unwrap();
+            assert_eq!(decoded, event);
+        }
+    }
+
+    #[test]
+    fn event_with_data_before_type_keeps_field_errors() {
+        let data = Value::Map(vec![(Value::from("threshold"), Value::from("full"))]);
+        let bytes = to_vec(&Value::Map(vec![
+            (Value::from("type"), Value::from("storage_full")),
+            (Value::from("data"), data),
+        ]))
+        .unwrap();
+
+        let error = from_slice::<Event>(&bytes).unwrap_err().to_string();
+        assert!(error.contains("expected u8"), "{error}");
+    }
+}
@@ -0,0 +1,570 @@
+//! Compact serde representations for constrained transports, eg: msgpack over cellular links.
+//! Wrapping a value in `Compact` serializes it with short field names and integer enum tags.
+
+use crate::events::{Event, EventKind};
+use crate::modem::ModemStatusUpdateState;
+use crate::sms::{SmsMessage, SmsPartialDeliveryReport};
+use serde::de::{self, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+
+/// Selects the compact representation of the wrapped value, eg: `Compact<SmsMessage>`.
+/// Events are encoded as a `(kind, data)` tuple, where the kind is its index in `EventKind::ALL`,
+/// or `u8::MAX` for unknown events.
+/// Events without a compact form keep their regular representation as the data.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Compact<T>(pub T);
+impl<T> Compact<T> {
+    /// Get the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+impl<T> From<T> for Compact<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SmsMessageRepr<'a> {
+    #[serde(rename = "i", default, skip_serializing_if = "Option::is_none")]
+    message_id: Option<i64>,
+
+    #[serde(rename = "p")]
+    phone_number: Cow<'a, str>,
+
+    #[serde(rename = "c")]
+    message_content: Cow<'a, str>,
+
+    #[serde(rename = "r", default, skip_serializing_if = "Option::is_none")]
+    message_reference: Option<u8>,
+
+    #[serde(rename = "o")]
+    is_outgoing: bool,
+
+    #[serde(rename = "t", default, skip_serializing_if = "Option::is_none")]
+    created_at: Option<u32>,
+
+    #[serde(rename = "d", default, skip_serializing_if = "Option::is_none")]
+    completed_at: Option<u32>,
+
+    #[serde(rename = "s", default, skip_serializing_if = "Option::is_none")]
+    status: Option<u8>,
+}
+impl<'a> From<&'a SmsMessage> for SmsMessageRepr<'a> {
+    fn from(value: &'a SmsMessage) -> Self {
+        Self {
+            message_id: value.message_id,
+            phone_number: Cow::Borrowed(&value.phone_number),
+            message_content: Cow::Borrowed(&value.message_content),
+            message_reference: value.message_reference,
+            is_outgoing: value.is_outgoing,
+            created_at: value.created_at,
+            completed_at: value.completed_at,
+            status: value.status,
+        }
+    }
+}
+impl From<SmsMessageRepr<'_>> for SmsMessage {
+    fn from(value: SmsMessageRepr<'_>) -> Self {
+        Self {
+            message_id: value.message_id,
+            phone_number: value.phone_number.into(),
+            message_content: value.message_content.into(),
+            message_reference: value.message_reference,
+            is_outgoing: value.is_outgoing,
+            created_at: value.created_at,
+            completed_at: value.completed_at,
+            status: value.status,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct DeliveryReportRepr<'a> {
+    #[serde(rename = "p")]
+    phone_number: Cow<'a, str>,
+
+    #[serde(rename = "r")]
+    reference_id: u8,
+
+    #[serde(rename = "s")]
+    status: u8,
+}
+impl<'a> From<&'a SmsPartialDeliveryReport> for DeliveryReportRepr<'a> {
+    fn from(value: &'a SmsPartialDeliveryReport) -> Self {
+        Self {
+            phone_number: Cow::Borrowed(&value.phone_number),
+            reference_id: value.reference_id,
+            status: value.status,
+        }
+    }
+}
+impl From<DeliveryReportRepr<'_>> for SmsPartialDeliveryReport {
+    fn from(value: DeliveryReportRepr<'_>) -> Self {
+        Self {
+            phone_number: value.phone_number.into_owned(),
+            reference_id: value.reference_id,
+            status: value.status,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct OutgoingMessageRepr<'a> {
+    #[serde(rename = "m")]
+    message: SmsMessageRepr<'a>,
+
+    #[serde(rename = "o", default, skip_serializing_if = "Option::is_none")]
+    origin_client_id: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DeliveryRepr<'a> {
+    #[serde(rename = "i")]
+    message_id: i64,
+
+    #[serde(rename = "r")]
+    report: DeliveryReportRepr<'a>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ModemStatusUpdateRepr<'a> {
+    #[serde(rename = "p", borrow)]
+    previous: ModemStateRepr<'a>,
+
+    #[serde(rename = "c", borrow)]
+    current: ModemStateRepr<'a>,
+}
+
+/// A modem state as an integer tag, or by name for states without one.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum ModemStateRepr<'a> {
+    Tag(u8),
+    Other(#[serde(borrow)] Cow<'a, str>),
+}
+
+fn modem_state_repr(state: &ModemStatusUpdateState) -> ModemStateRepr<'_> {
+    match state {
+        ModemStatusUpdateState::Startup => ModemStateRepr::Tag(0),
+        ModemStatusUpdateState::Online => ModemStateRepr::Tag(1),
+        ModemStatusUpdateState::ShuttingDown => ModemStateRepr::Tag(2),
+        ModemStatusUpdateState::Offline => ModemStateRepr::Tag(3),
+        ModemStatusUpdateState::Other(name) => ModemStateRepr::Other(Cow::Borrowed(name)),
+    }
+}
+
+fn modem_state_from_repr(repr: ModemStateRepr) -> Result<ModemStatusUpdateState, String> {
+    match repr {
+        ModemStateRepr::Tag(0) => Ok(ModemStatusUpdateState::Startup),
+        ModemStateRepr::Tag(1) => Ok(ModemStatusUpdateState::Online),
+        ModemStateRepr::Tag(2) => Ok(ModemStatusUpdateState::ShuttingDown),
+        ModemStateRepr::Tag(3) => Ok(ModemStatusUpdateState::Offline),
+        ModemStateRepr::Tag(tag) => Err(format!("Invalid modem status tag: {tag}")),
+        ModemStateRepr::Other(name) => Ok(ModemStatusUpdateState::Other(name.into_owned())),
+    }
+}
+
+#[cfg(feature = "gnss")]
+mod gnss {
+    use crate::gnss::{
+        CoarseLocation, Constellation, FixStatus, PositionReport, SatelliteInfo, TimedPosition,
+    };
+    use serde::{Deserialize, Serialize};
+    use std::borrow::Cow;
+
+    fn fix_status_tag(status: &FixStatus) -> u8 {
+        match status {
+            FixStatus::Unknown => 0,
+            FixStatus::NotFix => 1,
+            FixStatus::Fix2D => 2,
+            FixStatus::Fix3D => 3,
+        }
+    }
+
+    fn fix_status_from_tag(tag: u8) -> Result<FixStatus, String> {
+        match tag {
+            0 => Ok(FixStatus::Unknown),
+            1 => Ok(FixStatus::NotFix),
+            2 => Ok(FixStatus::Fix2D),
+            3 => Ok(FixStatus::Fix3D),
+            _ => Err(format!("Invalid fix status tag: {tag}")),
+        }
+    }
+
+    fn constellation_tag(constellation: Constellation) -> u8 {
+        match constellation {
+            Constellation::Unknown => 0,
+            Constellation::Gps => 1,
+            Constellation::Glonass => 2,
+            Constellation::Galileo => 3,
+            Constellation::BeiDou => 4,
+            Constellation::Qzss => 5,
+            Constellation::Sbas => 6,
+        }
+    }
+
+    fn constellation_from_tag(tag: u8) -> Result<Constellation, String> {
+        match tag {
+            0 => Ok(Constellation::Unknown),
+            1 => Ok(Constellation::Gps),
+            2 => Ok(Constellation::Glonass),
+            3 => Ok(Constellation::Galileo),
+            4 => Ok(Constellation::BeiDou),
+            5 => Ok(Constellation::Qzss),
+            6 => Ok(Constellation::Sbas),
+            _ => Err(format!("Invalid constellation tag: {tag}")),
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct SatelliteRepr {
+        #[serde(rename = "c")]
+        constellation: u8,
+
+        #[serde(rename = "n")]
+        prn: u16,
+
+        #[serde(rename = "s", default, skip_serializing_if = "Option::is_none")]
+        snr: Option<u8>,
+
+        #[serde(rename = "e", default, skip_serializing_if = "Option::is_none")]
+        elevation: Option<u8>,
+
+        #[serde(rename = "a", default, skip_serializing_if = "Option::is_none")]
+        azimuth: Option<u16>,
+
+        #[serde(rename = "u")]
+        used_in_fix: bool,
+    }
+    impl From<&SatelliteInfo> for SatelliteRepr {
+        fn from(value: &SatelliteInfo) -> Self {
+            Self {
+                constellation: constellation_tag(value.constellation),
+                prn: value.prn,
+                snr: value.snr,
+                elevation: value.elevation,
+                azimuth: value.azimuth,
+                used_in_fix: value.used_in_fix,
+            }
+        }
+    }
+    impl TryFrom<SatelliteRepr> for SatelliteInfo {
+        type Error = String;
+
+        fn try_from(value: SatelliteRepr) -> Result<Self, Self::Error> {
+            Ok(Self {
+                constellation: constellation_from_tag(value.constellation)?,
+                prn: value.prn,
+                snr: value.snr,
+                elevation: value.elevation,
+                azimuth: value.azimuth,
+                used_in_fix: value.used_in_fix,
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub(super) struct PositionReportRepr<'a> {
+        #[serde(rename = "r")]
+        run_status: bool,
+
+        #[serde(rename = "f")]
+        fix_status: bool,
+
+        #[serde(rename = "u")]
+        utc_time: Cow<'a, str>,
+
+        #[serde(rename = "la", default, skip_serializing_if = "Option::is_none")]
+        latitude: Option<f64>,
+
+        #[serde(rename = "lo", default, skip_serializing_if = "Option::is_none")]
+        longitude: Option<f64>,
+
+        #[serde(rename = "al", default, skip_serializing_if = "Option::is_none")]
+        msl_altitude: Option<f64>,
+
+        #[serde(rename = "gs", default, skip_serializing_if = "Option::is_none")]
+        ground_speed: Option<f32>,
+
+        #[serde(rename = "gc", default, skip_serializing_if = "Option::is_none")]
+        ground_course: Option<f32>,
+
+        #[serde(rename = "m")]
+        fix_mode: u8,
+
+        #[serde(rename = "h", default, skip_serializing_if = "Option::is_none")]
+        hdop: Option<f32>,
+
+        #[serde(rename = "p", default, skip_serializing_if = "Option::is_none")]
+        pdop: Option<f32>,
+
+        #[serde(rename = "v", default, skip_serializing_if = "Option::is_none")]
+        vdop: Option<f32>,
+
+        #[serde(rename = "gv", default, skip_serializing_if = "Option::is_none")]
+        gps_in_view: Option<u8>,
+
+        #[serde(rename = "gu", default, skip_serializing_if = "Option::is_none")]
+        gnss_used: Option<u8>,
+
+        #[serde(rename = "lv", default, skip_serializing_if = "Option::is_none")]
+        glonass_in_view: Option<u8>,
+
+        #[serde(rename = "s", default, skip_serializing_if = "Vec::is_empty")]
+        satellites: Vec<SatelliteRepr>,
+    }
+    impl<'a> From<&'a PositionReport> for PositionReportRepr<'a> {
+        fn from(value: &'a PositionReport) -> Self {
+            Self {
+                run_status: value.run_status,
+                fix_status: value.fix_status,
+                utc_time: Cow::Borrowed(&value.utc_time),
+                latitude: value.latitude,
+                longitude: value.longitude,
+                msl_altitude: value.msl_altitude,
+                ground_speed: value.ground_speed,
+                ground_course: value.ground_course,
+                fix_mode: fix_status_tag(&value.fix_mode),
+                hdop: value.hdop,
+                pdop: value.pdop,
+                vdop: value.vdop,
+                gps_in_view: value.gps_in_view,
+                gnss_used: value.gnss_used,
+                glonass_in_view: value.glonass_in_view,
+                satellites: value.satellites.iter().map(SatelliteRepr::from).collect(),
+            }
+        }
+    }
+    impl TryFrom<PositionReportRepr<'_>> for PositionReport {
+        type Error = String;
+
+        fn try_from(value: PositionReportRepr<'_>) -> Result<Self, Self::Error> {
+            Ok(Self {
+                run_status: value.run_status,
+                fix_status: value.fix_status,
+                utc_time: value.utc_time.into_owned(),
+                latitude: value.latitude,
+                longitude: value.longitude,
+                msl_altitude: value.msl_altitude,
+                ground_speed: value.ground_speed,
+                ground_course: value.ground_course,
+                fix_mode: fix_status_from_tag(value.fix_mode)?,
+                hdop: value.hdop,
+                pdop: value.pdop,
+                vdop: value.vdop,
+                gps_in_view: value.gps_in_view,
+                gnss_used: value.gnss_used,
+                glonass_in_view: value.glonass_in_view,
+                satellites: value
+                    .satellites
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub(super) struct TimedPositionRepr<'a> {
+        #[serde(rename = "p")]
+        report: PositionReportRepr<'a>,
+
+        #[serde(rename = "t")]
+        received_at: u64,
+
+        #[serde(rename = "c", default, skip_serializing_if = "Option::is_none")]
+        coarse: Option<Cow<'a, CoarseLocation>>,
+    }
+    impl<'a> From<&'a TimedPosition> for TimedPositionRepr<'a> {
+        fn from(value: &'a TimedPosition) -> Self {
+            Self {
+                report: PositionReportRepr::from(&value.report),
+                received_at: value.received_at,
+                coarse: value.coarse.as_ref().map(Cow::Borrowed),
+            }
+        }
+    }
+    impl TryFrom<TimedPositionRepr<'_>> for TimedPosition {
+        type Error = String;
+
+        fn try_from(value: TimedPositionRepr<'_>) -> Result<Self, Self::Error> {
+            Ok(Self {
+                report: value.report.try_into()?,
+                received_at: value.received_at,
+                coarse: value.coarse.map(Cow::into_owned),
+            })
+        }
+    }
+}
+
+impl Serialize for Compact<SmsMessage> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SmsMessageRepr::from(&self.0).serialize(serializer)
+    }
+}
+impl<'de> Deserialize<'de> for Compact<SmsMessage> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SmsMessageRepr::deserialize(deserializer).map(|repr| Compact(repr.into()))
+    }
+}
+
+impl Serialize for Compact<SmsPartialDeliveryReport> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DeliveryReportRepr::from(&self.0).serialize(serializer)
+    }
+}
+impl<'de> Deserialize<'de> for Compact<SmsPartialDeliveryReport> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        DeliveryReportRepr::deserialize(deserializer).map(|repr| Compact(repr.into()))
+    }
+}
+
+#[cfg(feature = "gnss")]
+impl Serialize for Compact<crate::gnss::PositionReport> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        gnss::PositionReportRepr::from(&self.0).serialize(serializer)
+    }
+}
+#[cfg(feature = "gnss")]
+impl<'de> Deserialize<'de> for Compact<crate::gnss::PositionReport> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        gnss::PositionReportRepr::deserialize(deserializer)?
+            .try_into()
+            .map(Compact)
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "gnss")]
+impl Serialize for Compact<crate::gnss::TimedPosition> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        gnss::TimedPositionRepr::from(&self.0).serialize(serializer)
+    }
+}
+#[cfg(feature = "gnss")]
+impl<'de> Deserialize<'de> for Compact<crate::gnss::TimedPosition> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        gnss::TimedPositionRepr::deserialize(deserializer)?
+            .try_into()
+            .map(Compact)
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Compact<Event> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&EventKind::from(&self.0).index())?;
+        match &self.0 {
+            Event::IncomingMessage(message) => {
+                tuple.serialize_element(&SmsMessageRepr::from(message))?;
+            }
+            Event::OutgoingMessage {
+                message,
+                origin_client_id,
+            } => tuple.serialize_element(&OutgoingMessageRepr {
+                message: message.into(),
+                origin_client_id: *origin_client_id,
+            })?,
+            Event::DeliveryReport { message_id, report } => {
+                tuple.serialize_element(&DeliveryRepr {
+                    message_id: *message_id,
+                    report: report.into(),
+                })?;
+            }
+            Event::ModemStatusUpdate { previous, current } => {
+                tuple.serialize_element(&ModemStatusUpdateRepr {
+                    previous: modem_state_repr(previous),
+                    current: modem_state_repr(current),
+                })?;
+            }
+
+            #[cfg(feature = "gnss")]
+            Event::GnssPositionReport(position) => {
+                tuple.serialize_element(&gnss::TimedPositionRepr::from(position))?;
+            }
+
+            other => tuple.serialize_element(other)?,
+        }
+        tuple.end()
+    }
+}
+impl<'de> Deserialize<'de> for Compact<Event> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(2, CompactEventVisitor)
+    }
+}
+
+struct CompactEventVisitor;
+impl<'de> Visitor<'de> for CompactEventVisitor {
+    type Value = Compact<Event>;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a compact event (kind, data) tuple")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let tag: u8 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let kind = EventKind::ALL
+            .get(usize::from(tag))
+            .copied()
+            .or((tag == EventKind::Unknown.index()).then_some(EventKind::Unknown))
+            .ok_or_else(|| de::Error::custom(format!("Invalid event kind tag: {tag}")))?;
+
+        let event = match kind {
+            EventKind::IncomingMessage => {
+                Event::IncomingMessage(next_data::<SmsMessageRepr, _>(&mut seq)?.into())
+            }
+            EventKind::OutgoingMessage => {
+                let repr: OutgoingMessageRepr = next_data(&mut seq)?;
+                Event::OutgoingMessage {
+                    message: repr.message.into(),
+                    origin_client_id: repr.origin_client_id,
+                }
+            }
+            EventKind::DeliveryReport => {
+                let repr: DeliveryRepr = next_data(&mut seq)?;
+                Event::DeliveryReport {
+                    message_id: repr.message_id,
+                    report: repr.report.into(),
+                }
+            }
+            EventKind::ModemStatusUpdate => {
+                let repr: ModemStatusUpdateRepr = next_data(&mut seq)?;
+                Event::ModemStatusUpdate {
+                    previous: modem_state_from_repr(repr.previous).map_err(de::Error::custom)?,
+                    current: modem_state_from_repr(repr.current).map_err(de::Error::custom)?,
+                }
+            }
+
+            #[cfg(feature = "gnss")]
+            EventKind::GNSSPositionReport => {
+                let repr: gnss::TimedPositionRepr = next_data(&mut seq)?;
+                Event::GnssPositionReport(repr.try_into().map_err(de::Error::custom)?)
+            }
+
+            _ => {
+                let event: Event = next_data(&mut seq)?;
+                if EventKind::from(&event) != kind {
+                    return Err(de::Error::custom(format!(
+                        "Event data does not match kind: {kind}"
+                    )));
+                }
+                event
+            }
+        };
+        Ok(Compact(event))
+    }
+}
+
+/// Read the data element of a compact event tuple.
+fn next_data<'de, T: Deserialize<'de>, A: SeqAccess<'de>>(seq: &mut A) -> Result<T, A::Error> {
+    seq.next_element()?
+        .ok_or_else(|| de::Error::invalid_length(1, &"a compact event (kind, data) tuple"))
+}
@@ -1,9 +1,24 @@
 //! Events that are sent via webhook or websocket.
 
-use serde::{Deserialize, Serialize};
+mod content;
+
+use content::Content;
+use serde::{Deserialize, Serialize, de};
+use std::borrow::Cow;
+
+pub use uuid::Uuid;
 
 /// The Kind of Event.
-#[derive(Eq, PartialEq, Hash, Debug, Clone, Copy, Deserialize)]
+#[derive(Eq, PartialEq, Hash, Debug, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[non_exhaustive]
 pub enum EventKind {
     /// New SMS message received.
     #[serde(rename = "incoming")]
@@ -28,15 +43,89 @@ pub enum EventKind {
     /// WebSocket connection status update (client-side only).
     #[serde(rename = "websocket_connection_update")]
     WebsocketConnectionUpdate,
+
+    /// GNSS receiver power state update.
+    #[serde(rename = "gnss_power_state_update")]
+    GNSSPowerStateUpdate,
+
+    /// GNSS fix acquired after power on, with time to first fix metrics.
+    #[serde(rename = "gnss_fix_acquired")]
+    GNSSFixAcquired,
+
+    /// USSD session reply received.
+    #[serde(rename = "ussd_response")]
+    UssdResponse,
+
+    /// Incoming voice call.
+    #[serde(rename = "incoming_call")]
+    IncomingCall,
+
+    /// SIM card status or ICCID change.
+    #[serde(rename = "sim_status_change")]
+    SimStatusChange,
+
+    /// Periodic signal strength sample.
+    #[serde(rename = "signal_strength_update")]
+    SignalStrengthUpdate,
+
+    /// SMS storage usage crossed the warning threshold.
+    #[serde(rename = "storage_full")]
+    StorageFull,
+
+    /// Webhook delivery failed after exhausting all retries.
+    #[serde(rename = "webhook_delivery_failed")]
+    WebhookDeliveryFailed,
+
+    /// Periodic summary of activity.
+    #[serde(rename = "digest")]
+    Digest,
+
+    /// Domain specific event emitted by a server plugin.
+    #[serde(rename = "custom")]
+    Custom,
+
+    /// Modem battery charge is low or it started discharging.
+    #[serde(rename = "battery_low")]
+    BatteryLow,
+
+    /// A position report entered or exited a geofence.
+    #[serde(rename = "geofence_transition")]
+    GeofenceTransition,
+
+    /// An event type this version doesn't know.
+    /// This has no bit, so it's never part of an `EventKindSet`.
+    #[serde(rename = "unknown", other)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
+    Unknown,
 }
 impl EventKind {
     /// Total number of `EventKind`'s.
-    pub const COUNT: usize = 6;
+    pub const COUNT: usize = Self::ALL.len();
 
-    /// Make the `EventKind` into it's u8 bit representation.
-    #[inline]
-    #[must_use]
-    pub const fn to_bit(self) -> u8 {
+    /// Every `EventKind`, in bit order.
+    pub const ALL: &[EventKind] = &[
+        EventKind::IncomingMessage,
+        EventKind::OutgoingMessage,
+        EventKind::DeliveryReport,
+        EventKind::ModemStatusUpdate,
+        EventKind::GNSSPositionReport,
+        EventKind::WebsocketConnectionUpdate,
+        EventKind::GNSSPowerStateUpdate,
+        EventKind::GNSSFixAcquired,
+        EventKind::UssdResponse,
+        EventKind::IncomingCall,
+        EventKind::SimStatusChange,
+        EventKind::SignalStrengthUpdate,
+        EventKind::StorageFull,
+        EventKind::WebhookDeliveryFailed,
+        EventKind::Digest,
+        EventKind::Custom,
+        EventKind::BatteryLow,
+        EventKind::GeofenceTransition,
+    ];
+
+    /// Get the bit used to represent the `EventKind` in an `EventKindSet`.
+    const fn bit(self) -> u32 {
         match self {
             EventKind::IncomingMessage => 1 << 0,
             EventKind::OutgoingMessage => 1 << 1,
@@ -44,34 +133,131 @@ impl EventKind {
             EventKind::ModemStatusUpdate => 1 << 3,
             EventKind::GNSSPositionReport => 1 << 4,
             EventKind::WebsocketConnectionUpdate => 1 << 5,
+            EventKind::GNSSPowerStateUpdate => 1 << 6,
+            EventKind::GNSSFixAcquired => 1 << 7,
+            EventKind::UssdResponse => 1 << 8,
+            EventKind::IncomingCall => 1 << 9,
+            EventKind::SimStatusChange => 1 << 10,
+            EventKind::SignalStrengthUpdate => 1 << 11,
+            EventKind::StorageFull => 1 << 12,
+            EventKind::WebhookDeliveryFailed => 1 << 13,
+            EventKind::Digest => 1 << 14,
+            EventKind::Custom => 1 << 15,
+            EventKind::BatteryLow => 1 << 16,
+            EventKind::GeofenceTransition => 1 << 17,
+            EventKind::Unknown => 0,
+        }
+    }
+
+    /// Get the index of the `EventKind` in `ALL`, used as a compact integer tag.
+    /// `Unknown` isn't in `ALL`, so it uses `u8::MAX`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) const fn index(self) -> u8 {
+        match self {
+            EventKind::Unknown => u8::MAX,
+            _ => self.bit().trailing_zeros() as u8,
+        }
+    }
+
+    /// Get the serialized name of the `EventKind`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            EventKind::IncomingMessage => "incoming",
+            EventKind::OutgoingMessage => "outgoing",
+            EventKind::DeliveryReport => "delivery",
+            EventKind::ModemStatusUpdate => "modem_status_update",
+            EventKind::GNSSPositionReport => "gnss_position_report",
+            EventKind::WebsocketConnectionUpdate => "websocket_connection_update",
+            EventKind::GNSSPowerStateUpdate => "gnss_power_state_update",
+            EventKind::GNSSFixAcquired => "gnss_fix_acquired",
+            EventKind::UssdResponse => "ussd_response",
+            EventKind::IncomingCall => "incoming_call",
+            EventKind::SimStatusChange => "sim_status_change",
+            EventKind::SignalStrengthUpdate => "signal_strength_update",
+            EventKind::StorageFull => "storage_full",
+            EventKind::WebhookDeliveryFailed => "webhook_delivery_failed",
+            EventKind::Digest => "digest",
+            EventKind::Custom => "custom",
+            EventKind::BatteryLow => "battery_low",
+            EventKind::GeofenceTransition => "geofence_transition",
+            EventKind::Unknown => "unknown",
         }
     }
 
+    /// Make the `EventKind` into it's u32 bit representation.
+    #[deprecated(note = "use `EventKindSet` instead of raw bitmasks")]
+    #[inline]
+    #[must_use]
+    pub const fn to_bit(self) -> u32 {
+        self.bit()
+    }
+
     /// Create a bitmask with all server `EventKind`'s.
+    #[deprecated(note = "use `EventKindSet::all` instead")]
     #[inline]
     #[must_use]
-    pub const fn all_bits() -> u8 {
-        (1 << 0) | (1 << 1) | (1 << 2) | (1 << 3) | (1 << 4)
+    pub const fn all_bits() -> u32 {
+        EventKindSet::all().bits()
     }
 
     /// Takes a set of `EventKinds` and returns its mask.
+    #[deprecated(note = "collect into an `EventKindSet` instead")]
     #[inline]
     #[must_use]
-    pub fn events_to_mask(events: &[EventKind]) -> u8 {
-        events.iter().fold(0, |acc, event| acc | event.to_bit())
+    pub fn events_to_mask(events: &[EventKind]) -> u32 {
+        EventKindSet::from(events).bits()
     }
 }
+
+// `ALL` must hold every variant except `Unknown`, each at the index of its bit. `bit` matches
+// exhaustively, so a new variant needs a bit to compile. `Unknown` is always the last variant,
+// as `serde(other)` requires, so its discriminant is the amount of other variants. Together with
+// the distinct bits, this means a variant missing from `ALL` fails to compile.
+const _: () = {
+    assert!(
+        EventKind::ALL.len() == EventKind::Unknown as usize,
+        "EventKind::ALL is missing a variant"
+    );
+    let mut i = 0;
+    while i < EventKind::ALL.len() {
+        assert!(
+            EventKind::ALL[i].bit() == 1 << i,
+            "EventKind::ALL must be in bit order"
+        );
+        i += 1;
+    }
+};
+
 impl From<&Event> for EventKind {
     fn from(value: &Event) -> Self {
         match value {
             Event::IncomingMessage(_) => EventKind::IncomingMessage,
-            Event::OutgoingMessage(_) => EventKind::OutgoingMessage,
+            Event::OutgoingMessage { .. } => EventKind::OutgoingMessage,
             Event::DeliveryReport { .. } => EventKind::DeliveryReport,
             Event::ModemStatusUpdate { .. } => EventKind::ModemStatusUpdate,
-            Event::WebsocketConnectionUpdate { .. } => EventKind::WebsocketConnectionUpdate,
+            Event::UssdResponse(_) => EventKind::UssdResponse,
+            Event::IncomingCall(_) => EventKind::IncomingCall,
+            Event::SimStatusChange { .. } => EventKind::SimStatusChange,
+            Event::SignalStrengthUpdate { .. } => EventKind::SignalStrengthUpdate,
+            Event::StorageFull { .. } => EventKind::StorageFull,
+            Event::WebhookDeliveryFailed { .. } => EventKind::WebhookDeliveryFailed,
+            Event::Digest(_) => EventKind::Digest,
+            Event::Custom { .. } => EventKind::Custom,
+            Event::BatteryLow { .. } => EventKind::BatteryLow,
+            Event::Unknown { .. } => EventKind::Unknown,
 
             #[cfg(feature = "gnss")]
             Event::GnssPositionReport(_) => EventKind::GNSSPositionReport,
+
+            #[cfg(feature = "gnss")]
+            Event::GnssPowerStateUpdate { .. } => EventKind::GNSSPowerStateUpdate,
+
+            #[cfg(feature = "gnss")]
+            Event::GnssFixAcquired(_) => EventKind::GNSSFixAcquired,
+
+            #[cfg(feature = "gnss")]
+            Event::GeofenceTransition(_) => EventKind::GeofenceTransition,
         }
     }
 }
@@ -81,21 +267,193 @@ impl TryFrom<&str> for EventKind {
     /// Convert a str into an `EventKind`.
     #[inline]
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "incoming" => Ok(EventKind::IncomingMessage),
-            "outgoing" => Ok(EventKind::OutgoingMessage),
-            "delivery" => Ok(EventKind::DeliveryReport),
-            "modem_status_update" => Ok(EventKind::ModemStatusUpdate),
-            "websocket_connection_upgrade" => Ok(EventKind::WebsocketConnectionUpdate),
-            "gnss_position_report" => Ok(EventKind::GNSSPositionReport),
-            _ => Err(format!("Unknown event type {value}")),
+        value.parse()
+    }
+}
+impl std::str::FromStr for EventKind {
+    type Err = String;
+
+    /// Parse an `EventKind` from its serialized name, including `unknown`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EventKind::ALL
+            .iter()
+            .chain([&EventKind::Unknown])
+            .find(|kind| kind.as_str() == s)
+            .copied()
+            .ok_or_else(|| format!("Unknown event type {s}"))
+    }
+}
+impl std::fmt::Display for EventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A set of `EventKind`s, stored as a bitmask.
+/// Serialized as an array of event kind names, but a legacy integer bitmask
+/// is also accepted when deserializing.
+#[derive(Eq, PartialEq, Hash, Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(as = "Vec<EventKind>"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct EventKindSet(u32);
+impl EventKindSet {
+    /// Create an empty set.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Create a set of all server `EventKind`s, excluding client-side only kinds.
+    #[must_use]
+    pub const fn all() -> Self {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < EventKind::COUNT {
+            bits |= EventKind::ALL[i].bit();
+            i += 1;
+        }
+        Self(bits & !EventKind::WebsocketConnectionUpdate.bit())
+    }
+
+    /// Create a set from a raw bitmask, ignoring unknown bits.
+    #[must_use]
+    pub const fn from_bits(bits: u32) -> Self {
+        let mut known = 0;
+        let mut i = 0;
+        while i < EventKind::COUNT {
+            known |= EventKind::ALL[i].bit();
+            i += 1;
+        }
+        Self(bits & known)
+    }
+
+    /// Get the raw bitmask.
+    #[must_use]
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns a copy of the set with an `EventKind` added.
+    #[must_use]
+    pub const fn with(self, kind: EventKind) -> Self {
+        Self(self.0 | kind.bit())
+    }
+
+    /// Add an `EventKind`, returning true if it was not already present.
+    pub fn insert(&mut self, kind: EventKind) -> bool {
+        let added = !self.contains(kind);
+        self.0 |= kind.bit();
+        added
+    }
+
+    /// Remove an `EventKind`, returning true if it was present.
+    pub fn remove(&mut self, kind: EventKind) -> bool {
+        let removed = self.contains(kind);
+        self.0 &= !kind.bit();
+        removed
+    }
+
+    /// Check if the set contains an `EventKind`.
+    #[must_use]
+    pub const fn contains(self, kind: EventKind) -> bool {
+        self.0 & kind.bit() != 0
+    }
+
+    /// Returns true if the set contains no `EventKind`s.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Get the amount of `EventKind`s in the set.
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Iterate over the `EventKind`s in the set, in bit order.
+    pub fn iter(self) -> impl Iterator<Item = EventKind> {
+        EventKind::ALL
+            .iter()
+            .copied()
+            .filter(move |kind| self.contains(*kind))
+    }
+}
+impl From<&[EventKind]> for EventKindSet {
+    fn from(value: &[EventKind]) -> Self {
+        value.iter().copied().collect()
+    }
+}
+impl FromIterator<EventKind> for EventKindSet {
+    fn from_iter<T: IntoIterator<Item = EventKind>>(iter: T) -> Self {
+        let mut set = Self::empty();
+        set.extend(iter);
+        set
+    }
+}
+impl Extend<EventKind> for EventKindSet {
+    fn extend<T: IntoIterator<Item = EventKind>>(&mut self, iter: T) {
+        for kind in iter {
+            self.insert(kind);
+        }
+    }
+}
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for EventKindSet {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "EventKindSet".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        <Vec<EventKind>>::json_schema(generator)
+    }
+}
+impl Serialize for EventKindSet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(EventKind::as_str))
+    }
+}
+impl<'de> Deserialize<'de> for EventKindSet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum EventKindSetRepr {
+            Names(Vec<EventKind>),
+            Bits(u32),
         }
+
+        Ok(match EventKindSetRepr::deserialize(deserializer)? {
+            EventKindSetRepr::Names(kinds) => kinds.into_iter().collect(),
+            EventKindSetRepr::Bits(bits) => Self::from_bits(bits),
+        })
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for EventKindSet {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_bits(u.arbitrary()?))
     }
 }
 
 /// Event types that can be sent by the server.
+/// Transport specific messages, such as websocket connection updates, are
+/// part of `WebsocketMessage` instead, which wraps these events.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
-#[serde(tag = "type", content = "data")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all_fields = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize),
+    borsh(use_discriminant = true)
+)]
+#[serde(tag = "type", content = "data", remote = "Self")]
+#[repr(u8)]
+#[non_exhaustive]
 pub enum Event {
     /// New SMS message received.
     #[serde(rename = "incoming")]
@@ -103,7 +461,16 @@ pub enum Event {
 
     /// SMS message being sent from API or other connected client.
     #[serde(rename = "outgoing")]
-    OutgoingMessage(crate::sms::SmsMessage),
+    OutgoingMessage {
+        /// The message being sent.
+        #[serde(flatten)]
+        message: crate::sms::SmsMessage,
+
+        /// The websocket client id that sent the message, if it was sent over a websocket.
+        /// This allows a client to recognise the echo of its own outgoing messages.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        origin_client_id: Option<u64>,
+    },
 
     /// Delivery report update.
     #[serde(rename = "delivery")]
@@ -127,18 +494,1278 @@ pub enum Event {
         current: crate::modem::ModemStatusUpdateState,
     },
 
-    /// WebSocket connection status update (client-side only).
-    /// This message is generated locally when there is a connection or disconnection.
-    WebsocketConnectionUpdate {
-        /// Connection status: true = connected, false = disconnected
-        connected: bool,
+    /// USSD session reply received asynchronously from the network.
+    /// The session id can be used to send a follow up in the same session.
+    #[serde(rename = "ussd_response")]
+    UssdResponse(crate::ussd::UssdResponse),
+
+    /// Incoming voice call, eg: for missed call notifications.
+    #[serde(rename = "incoming_call")]
+    IncomingCall(crate::call::IncomingCall),
+
+    /// SIM card status change, eg: removed or locked, or a different SIM was inserted.
+    #[serde(rename = "sim_status_change")]
+    SimStatusChange {
+        /// Previous status from last update.
+        previous: crate::modem::SimStatus,
+
+        /// Current status after update.
+        current: crate::modem::SimStatus,
+
+        /// The ICCID of the current SIM, if it could be read.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        iccid: Option<String>,
 
-        /// If connection is false, will the client attempt to automatically reconnect?
-        reconnect: bool,
+        /// The ICCID of the previous SIM, if it differs from the current SIM.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        previous_iccid: Option<String>,
+    },
+
+    /// Periodic signal strength sample, for charting signal over time.
+    #[serde(rename = "signal_strength_update")]
+    SignalStrengthUpdate {
+        /// The sampled signal strength.
+        signal: crate::modem::SignalStrength,
+
+        /// Unix timestamp when the signal strength was sampled.
+        sampled_at: u32,
+    },
+
+    /// SMS storage usage crossed the configured threshold, so messages should
+    /// be purged before incoming messages start being rejected.
+    #[serde(rename = "storage_full")]
+    StorageFull {
+        /// The storage usage when the threshold was crossed.
+        status: crate::sms::SmsStorageStatus,
+
+        /// The configured usage threshold percentage.
+        threshold: u8,
+    },
+
+    /// A webhook delivery failed after exhausting all retries, so the event
+    /// was not received by that webhook.
+    #[serde(rename = "webhook_delivery_failed")]
+    WebhookDeliveryFailed {
+        /// The identifier of the webhook the delivery was for.
+        webhook_id: String,
+
+        /// The `event_id` of the event that could not be delivered.
+        original_event_id: Uuid,
+
+        /// The amount of delivery attempts made.
+        attempts: u8,
+
+        /// The error from the final delivery attempt.
+        final_error: String,
+    },
+
+    /// A summary of activity over a window, for low-power clients that wake
+    /// rarely and don't want every event.
+    #[serde(rename = "digest")]
+    Digest(EventDigest),
+
+    /// Domain specific event emitted by a server plugin.
+    /// Consumers should match on the name and ignore names they don't know.
+    #[serde(rename = "custom")]
+    Custom {
+        /// The plugin defined event name, eg: `door_opened`.
+        name: String,
+
+        /// The plugin defined event data.
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json_value))]
+        #[cfg_attr(
+            feature = "binary-codec",
+            borsh(
+                serialize_with = "borsh_json_value::serialize",
+                deserialize_with = "borsh_json_value::deserialize"
+            )
+        )]
+        data: serde_json::Value,
+    },
+
+    /// The modem battery charge fell below the configured threshold, or the
+    /// battery started discharging, eg: after losing external power.
+    #[serde(rename = "battery_low")]
+    BatteryLow {
+        /// The battery level when the event was emitted.
+        battery: crate::modem::BatteryLevel,
+
+        /// The configured charge threshold percentage.
+        threshold: u8,
     },
 
     /// An unsolicited position report from GNSS.
     #[cfg(feature = "gnss")]
     #[serde(rename = "gnss_position_report")]
-    GnssPositionReport(crate::gnss::PositionReport),
+    GnssPositionReport(crate::gnss::TimedPosition),
+
+    /// GNSS receiver power state update, eg: powered on and acquiring a fix.
+    #[cfg(feature = "gnss")]
+    #[serde(rename = "gnss_power_state_update")]
+    GnssPowerStateUpdate {
+        /// Previous state from last update.
+        previous: crate::gnss::GnssPowerState,
+
+        /// Current state after update.
+        current: crate::gnss::GnssPowerState,
+    },
+
+    /// GNSS fix acquired after power on, with time to first fix metrics.
+    #[cfg(feature = "gnss")]
+    #[serde(rename = "gnss_fix_acquired")]
+    GnssFixAcquired(crate::gnss::FixMetrics),
+
+    /// A position report entered or exited a geofence.
+    #[cfg(feature = "gnss")]
+    #[serde(rename = "geofence_transition")]
+    GeofenceTransition(crate::gnss::geofence::GeofenceEvent),
+
+    /// An event type this version doesn't know, kept as raw JSON so it can still be
+    /// logged or forwarded. Known types with invalid data still fail to deserialize.
+    /// Has a fixed discriminant so its Borsh tag doesn't depend on enabled features.
+    #[serde(skip)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
+    Unknown {
+        /// The serialized event type, eg: `sms_failed`.
+        kind: String,
+
+        /// The raw event data.
+        #[cfg_attr(
+            feature = "binary-codec",
+            borsh(
+                serialize_with = "borsh_json_value::serialize",
+                deserialize_with = "borsh_json_value::deserialize"
+            )
+        )]
+        data: serde_json::Value,
+    } = u8::MAX,
+}
+
+impl Serialize for Event {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        match self {
+            Self::Unknown { kind, data } => {
+                let mut state = serializer.serialize_struct("Event", 2)?;
+                state.serialize_field("type", kind)?;
+                state.serialize_field("data", data)?;
+                state.end()
+            }
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for Event {
+    /// Read the event type first, so known types use the tagged representation with precise
+    /// errors. Only unknown types become `Event::Unknown`.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let human_readable = deserializer.is_human_readable();
+        deserializer.deserialize_struct("Event", &["type", "data"], EventVisitor { human_readable })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum EventField {
+    Type,
+    Data,
+    #[serde(other)]
+    Other,
+}
+
+struct EventVisitor {
+    human_readable: bool,
+}
+impl<'de> de::Visitor<'de> for EventVisitor {
+    type Value = Event;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("an event with a type and data")
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Event, A::Error> {
+        let tagged = TaggedMap::read(map, self.human_readable)?;
+        if is_known_event_type(&tagged.kind) {
+            Event::deserialize(tagged.into_deserializer())
+        } else {
+            tagged.into_unknown_event()
+        }
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Event, A::Error> {
+        let kind: String = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        if !is_known_event_type(&kind) {
+            return Ok(Event::Unknown {
+                kind,
+                data: seq.next_element()?.unwrap_or_default(),
+            });
+        }
+        Event::deserialize(de::value::SeqAccessDeserializer::new(ReplayType::new(
+            kind, seq,
+        )))
+    }
+}
+
+/// Check if an event type has its own variant, rather than being an `Event::Unknown`.
+fn is_known_event_type(kind: &str) -> bool {
+    match kind.parse::<EventKind>() {
+        Ok(EventKind::WebsocketConnectionUpdate | EventKind::Unknown) | Err(_) => false,
+        Ok(
+            EventKind::GNSSPositionReport
+            | EventKind::GNSSPowerStateUpdate
+            | EventKind::GNSSFixAcquired
+            | EventKind::GeofenceTransition,
+        ) => cfg!(feature = "gnss"),
+        Ok(_) => true,
+    }
+}
+
+/// A `type` and `data` map that has been read up to its type, so the type can choose
+/// how the rest is read. Data that comes before the type, eg: in deterministic CBOR
+/// which sorts keys, is buffered in the serde data model so it's read with the same
+/// values and errors as in place.
+pub(crate) struct TaggedMap<A> {
+    /// The serialized type.
+    pub(crate) kind: String,
+    data: Option<Content>,
+    human_readable: bool,
+    map: A,
+}
+impl<'de, A: de::MapAccess<'de>> TaggedMap<A> {
+    /// Read a map up to and including its type.
+    pub(crate) fn read(mut map: A, human_readable: bool) -> Result<Self, A::Error> {
+        let mut data = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                EventField::Type => {
+                    return Ok(Self {
+                        kind: map.next_value()?,
+                        data,
+                        human_readable,
+                        map,
+                    });
+                }
+                EventField::Data if data.is_some() => {
+                    return Err(de::Error::duplicate_field("data"));
+                }
+                EventField::Data => data = Some(map.next_value()?),
+                EventField::Other => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        Err(de::Error::missing_field("type"))
+    }
+
+    /// Replay the type and any buffered data ahead of the rest of the map,
+    /// eg: for a derived tagged representation.
+    pub(crate) fn into_deserializer(self) -> de::value::MapAccessDeserializer<ReplayType<A>> {
+        de::value::MapAccessDeserializer::new(ReplayType {
+            kind: Some(self.kind),
+            data: self.data,
+            human_readable: self.human_readable,
+            access: self.map,
+        })
+    }
+
+    /// Read the rest of the map as an `Event::Unknown`, keeping its data if there is any.
+    fn into_unknown_event(self) -> Result<Event, A::Error> {
+        let Self {
+            kind,
+            data,
+            human_readable,
+            mut map,
+        } = self;
+        let mut data = data
+            .map(|data| serde_json::Value::deserialize(data.into_deserializer(human_readable)))
+            .transpose()?;
+        while let Some(field) = map.next_key()? {
+            match field {
+                EventField::Data if data.is_some() => {
+                    return Err(de::Error::duplicate_field("data"));
+                }
+                EventField::Data => data = Some(map.next_value()?),
+                EventField::Type => return Err(de::Error::duplicate_field("type")),
+                EventField::Other => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(Event::Unknown {
+            kind,
+            data: data.unwrap_or_default(),
+        })
+    }
+}
+
+/// Replays an already read type, and any data buffered before it, ahead of the rest
+/// of a map or sequence, so it can be handed to a derived tagged deserializer.
+pub(crate) struct ReplayType<A> {
+    kind: Option<String>,
+    data: Option<Content>,
+    human_readable: bool,
+    access: A,
+}
+impl<A> ReplayType<A> {
+    /// Replay a type ahead of a sequence.
+    pub(crate) fn new(kind: String, access: A) -> Self {
+        Self {
+            kind: Some(kind),
+            data: None,
+            human_readable: true,
+            access,
+        }
+    }
+}
+impl<'de, A: de::MapAccess<'de>> de::MapAccess<'de> for ReplayType<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        let replayed = if self.kind.is_some() {
+            "type"
+        } else if self.data.is_some() {
+            "data"
+        } else {
+            return self.access.next_key_seed(seed);
+        };
+        seed.deserialize(de::value::StrDeserializer::new(replayed))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, A::Error> {
+        if let Some(kind) = self.kind.take() {
+            return seed.deserialize(de::value::StringDeserializer::new(kind));
+        }
+        match self.data.take() {
+            Some(data) => seed.deserialize(data.into_deserializer(self.human_readable)),
+            None => self.access.next_value_seed(seed),
+        }
+    }
+}
+impl<'de, A: de::SeqAccess<'de>> de::SeqAccess<'de> for ReplayType<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, A::Error> {
+        match self.kind.take() {
+            Some(kind) => seed
+                .deserialize(de::value::StringDeserializer::new(kind))
+                .map(Some),
+            None => self.access.next_element_seed(seed),
+        }
+    }
+}
+
+/// The current event envelope schema version.
+pub const SCHEMA_VERSION: u16 = 2;
+
+/// Envelope schema versions that can be deserialized, the current and previous version.
+/// Version 1 envelopes have no `schema_version` field.
+pub const SUPPORTED_SCHEMA_VERSIONS: [u16; 2] = [SCHEMA_VERSION - 1, SCHEMA_VERSION];
+
+/// An event with the metadata needed for ordering and deduplication,
+/// as delivered to webhook and websocket consumers.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[serde(try_from = "EventEnvelopeRepr")]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::SimpleObject))]
+pub struct EventEnvelope {
+    /// The envelope schema version this was serialized with.
+    #[cfg_attr(
+        feature = "arbitrary",
+        arbitrary(with = |u: &mut arbitrary::Unstructured| u.choose(&SUPPORTED_SCHEMA_VERSIONS).copied())
+    )]
+    pub schema_version: u16,
+
+    /// A unique identifier for the event, stable across redeliveries.
+    pub event_id: Uuid,
+
+    /// Monotonically increasing sequence number assigned by the server.
+    pub sequence: u64,
+
+    /// Unix timestamp in milliseconds when the event was emitted.
+    pub emitted_at: u64,
+
+    /// The wrapped event.
+    pub event: Event,
+}
+impl EventEnvelope {
+    /// Wrap an event emitted now.
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    #[must_use]
+    pub fn new(event_id: Uuid, sequence: u64, event: Event) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            event_id,
+            sequence,
+            emitted_at: crate::timestamp::Timestamp::now().as_unix_millis(),
+            event,
+        }
+    }
+
+    /// Get the kind of the wrapped event.
+    #[must_use]
+    pub fn kind(&self) -> EventKind {
+        EventKind::from(&self.event)
+    }
+
+    /// Get when the event was emitted.
+    #[must_use]
+    pub const fn emitted_timestamp(&self) -> crate::timestamp::Timestamp {
+        crate::timestamp::Timestamp::from_unix_millis(self.emitted_at)
+    }
+}
+#[cfg(feature = "avro")]
+impl EventEnvelope {
+    /// Encode the envelope as an Avro datum, using `avro::event_envelope_schema`.
+    ///
+    /// # Errors
+    /// Returns an error if the envelope cannot be serialized, or the sequence or
+    /// emission time don't fit in an Avro long.
+    pub fn to_avro(&self) -> Result<Vec<u8>, crate::avro::AvroError> {
+        crate::avro::encode_event_envelope(self)
+    }
+
+    /// Decode an envelope from an Avro datum.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid encoded envelope.
+    pub fn from_avro(bytes: &[u8]) -> Result<Self, crate::avro::AvroError> {
+        crate::avro::decode_event_envelope(bytes)
+    }
+}
+#[cfg(feature = "cbor")]
+impl EventEnvelope {
+    /// Encode the envelope as deterministic CBOR.
+    ///
+    /// # Errors
+    /// Returns an error if the envelope cannot be serialized.
+    pub fn to_cbor(&self) -> Result<Vec<u8>, crate::cbor::EncodeError> {
+        crate::cbor::to_vec(self)
+    }
+
+    /// Decode an envelope from CBOR.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid encoded envelope.
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, crate::cbor::DecodeError> {
+        crate::cbor::from_slice(bytes)
+    }
+}
+
+/// The serialized form of any supported `EventEnvelope` schema version.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+struct EventEnvelopeRepr {
+    #[serde(default = "legacy_schema_version")]
+    schema_version: u16,
+    event_id: Uuid,
+    sequence: u64,
+    emitted_at: u64,
+    event: Event,
+}
+impl TryFrom<EventEnvelopeRepr> for EventEnvelope {
+    type Error = String;
+
+    fn try_from(value: EventEnvelopeRepr) -> Result<Self, Self::Error> {
+        if !SUPPORTED_SCHEMA_VERSIONS.contains(&value.schema_version) {
+            return Err(format!(
+                "Unsupported event schema version {}, expected one of {SUPPORTED_SCHEMA_VERSIONS:?}",
+                value.schema_version
+            ));
+        }
+        Ok(Self {
+            schema_version: value.schema_version,
+            event_id: value.event_id,
+            sequence: value.sequence,
+            emitted_at: value.emitted_at,
+            event: value.event,
+        })
+    }
+}
+
+fn legacy_schema_version() -> u16 {
+    SCHEMA_VERSION - 1
+}
+
+/// Generate scalar custom event data, `serde_json::Value` has no `Arbitrary` impl.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_json_value(
+    u: &mut arbitrary::Unstructured<'_>,
+) -> arbitrary::Result<serde_json::Value> {
+    Ok(match u.int_in_range(0..=3)? {
+        0 => serde_json::Value::Null,
+        1 => serde_json::Value::Bool(u.arbitrary()?),
+        2 => serde_json::Value::from(u.arbitrary::<i64>()?),
+        _ => serde_json::Value::String(u.arbitrary()?),
+    })
+}
+
+/// Borsh encoding of custom event data as a JSON string, `serde_json::Value` has no Borsh impl.
+#[cfg(feature = "binary-codec")]
+mod borsh_json_value {
+    use borsh::io::{Error, ErrorKind, Read, Write};
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    pub(super) fn serialize<W: Write>(
+        value: &serde_json::Value,
+        writer: &mut W,
+    ) -> Result<(), Error> {
+        value.to_string().serialize(writer)
+    }
+
+    pub(super) fn deserialize<R: Read>(reader: &mut R) -> Result<serde_json::Value, Error> {
+        serde_json::from_str(&String::deserialize_reader(reader)?)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+}
+
+/// The request body sent to webhook receivers.
+/// Receivers should deduplicate on the event id, as failed deliveries are retried.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct WebhookPayload {
+    /// The identifier of the webhook this delivery is for.
+    pub webhook_id: String,
+
+    /// The delivery attempt, starting at 1.
+    pub attempt: u8,
+
+    /// The delivered event.
+    pub envelope: EventEnvelope,
+}
+impl WebhookPayload {
+    /// Create the first delivery attempt of an event.
+    pub fn new(webhook_id: impl Into<String>, envelope: EventEnvelope) -> Self {
+        Self {
+            webhook_id: webhook_id.into(),
+            attempt: 1,
+            envelope,
+        }
+    }
+
+    /// Get the payload for the next delivery attempt of the same event.
+    #[must_use]
+    pub fn retry(&self) -> Self {
+        Self {
+            attempt: self.attempt.saturating_add(1),
+            ..self.clone()
+        }
+    }
+
+    /// Get the key identifying this delivery attempt.
+    #[must_use]
+    pub fn dedup_key(&self) -> (Uuid, u8) {
+        (self.envelope.event_id, self.attempt)
+    }
+}
+
+/// A summary of activity over a window of time.
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct EventDigest {
+    /// Unix timestamp when the window started.
+    pub window_start: u32,
+
+    /// Unix timestamp when the window ended.
+    pub window_end: u32,
+
+    /// The amount of messages sent.
+    pub messages_sent: u32,
+
+    /// The amount of messages received.
+    pub messages_received: u32,
+
+    /// The amount of permanently failed delivery reports.
+    pub delivery_failures: u32,
+
+    /// The weakest known signal strength sampled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_signal: Option<crate::modem::SignalStrength>,
+
+    /// The strongest known signal strength sampled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_signal: Option<crate::modem::SignalStrength>,
+}
+impl EventDigest {
+    /// Create an empty digest for a window starting at a Unix timestamp.
+    #[must_use]
+    pub fn new(window_start: u32) -> Self {
+        Self {
+            window_start,
+            window_end: window_start,
+            ..Self::default()
+        }
+    }
+
+    /// Add an event to the digest.
+    pub fn record(&mut self, event: &Event) {
+        match event {
+            Event::IncomingMessage(_) => {
+                self.messages_received = self.messages_received.saturating_add(1);
+            }
+            Event::OutgoingMessage { .. } => {
+                self.messages_sent = self.messages_sent.saturating_add(1);
+            }
+            Event::DeliveryReport { report, .. }
+                if crate::sms::SmsDeliveryReportStatusCategory::from(report)
+                    == crate::sms::SmsDeliveryReportStatusCategory::Failed =>
+            {
+                self.delivery_failures = self.delivery_failures.saturating_add(1);
+            }
+            Event::SignalStrengthUpdate { signal, .. } if signal.is_known() => {
+                if self.min_signal.is_none_or(|min| signal.rssi < min.rssi) {
+                    self.min_signal = Some(*signal);
+                }
+                if self.max_signal.is_none_or(|max| signal.rssi > max.rssi) {
+                    self.max_signal = Some(*signal);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Check if the window is due to be closed at a Unix timestamp,
+    /// for a digest interval in seconds, eg: `WebsocketSubscription::digest_interval`.
+    #[must_use]
+    pub fn is_due(&self, now: u32, interval: u32) -> bool {
+        now >= self.window_start.saturating_add(interval)
+    }
+
+    /// Close the window at a Unix timestamp, returning the digest event.
+    #[must_use]
+    pub fn finish(mut self, window_end: u32) -> Event {
+        self.window_end = window_end;
+        Event::Digest(self)
+    }
+}
+
+/// The direction of a message event.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+pub enum MessageDirection {
+    /// Received messages.
+    Incoming,
+
+    /// Sent messages and their delivery reports.
+    Outgoing,
+}
+impl std::fmt::Display for MessageDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            MessageDirection::Incoming => "Incoming",
+            MessageDirection::Outgoing => "Outgoing",
+        })
+    }
+}
+
+/// A filter selecting which events are delivered, shared by websocket
+/// subscriptions and webhook configuration.
+/// Each predicate only applies to the events it relates to, eg: the phone
+/// number prefix does not exclude modem status updates.
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct EventFilter {
+    /// The kinds of events to receive.
+    pub events: EventKindSet,
+
+    /// Only receive message, delivery report and call events for phone numbers with this prefix.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone_number_prefix: Option<String>,
+
+    /// Only receive message and delivery report events in this direction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub direction: Option<MessageDirection>,
+
+    /// Only receive delivery reports that have permanently failed.
+    #[serde(default)]
+    pub only_failed_deliveries: bool,
+}
+impl EventFilter {
+    /// Create a filter for a set of `EventKind`s, with no other predicates.
+    #[must_use]
+    pub fn new(events: EventKindSet) -> Self {
+        Self {
+            events,
+            ..Self::default()
+        }
+    }
+
+    /// Only receive events for phone numbers with a prefix.
+    #[must_use]
+    pub fn with_phone_number_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.phone_number_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Only receive message events in a direction.
+    #[must_use]
+    pub fn with_direction(mut self, direction: MessageDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Set whether only permanently failed delivery reports are received.
+    #[must_use]
+    pub fn with_only_failed_deliveries(mut self, only_failed_deliveries: bool) -> Self {
+        self.only_failed_deliveries = only_failed_deliveries;
+        self
+    }
+
+    /// Check if an event passes the filter.
+    #[must_use]
+    pub fn matches(&self, event: &Event) -> bool {
+        if !self.events.contains(EventKind::from(event)) {
+            return false;
+        }
+        if let (Some(direction), Some(event_direction)) = (self.direction, event.direction())
+            && direction != event_direction
+        {
+            return false;
+        }
+        if self.only_failed_deliveries
+            && let Event::DeliveryReport { report, .. } = event
+            && crate::sms::SmsDeliveryReportStatusCategory::from(report)
+                != crate::sms::SmsDeliveryReportStatusCategory::Failed
+        {
+            return false;
+        }
+        let Some(prefix) = &self.phone_number_prefix else {
+            return true;
+        };
+        match event.phone_number() {
+            Some(phone_number) => phone_number.starts_with(prefix.as_str()),
+            None => !matches!(event, Event::IncomingCall(_)),
+        }
+    }
+}
+
+/// The delivery state of an outgoing message.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[non_exhaustive]
+pub enum MessageState {
+    /// The message is being sent by the modem.
+    Sending,
+
+    /// The message has been sent, however not yet delivered.
+    Sent,
+
+    /// The message has a temporary error, and sending will be retried by the carrier.
+    Retrying,
+
+    /// The message has been delivered.
+    Delivered,
+
+    /// The message has a permanent error and will not be retried.
+    Failed,
+}
+impl std::fmt::Display for MessageState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl MessageState {
+    /// Get the name of the `MessageState`, as stored in databases.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            MessageState::Sending => "Sending",
+            MessageState::Sent => "Sent",
+            MessageState::Retrying => "Retrying",
+            MessageState::Delivered => "Delivered",
+            MessageState::Failed => "Failed",
+        }
+    }
+
+    /// Get a `MessageState` from its name.
+    #[cfg(any(feature = "rusqlite", feature = "diesel"))]
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Sending" => Some(MessageState::Sending),
+            "Sent" => Some(MessageState::Sent),
+            "Retrying" => Some(MessageState::Retrying),
+            "Delivered" => Some(MessageState::Delivered),
+            "Failed" => Some(MessageState::Failed),
+            _ => None,
+        }
+    }
+
+    /// Returns true if no further state changes are expected.
+    #[must_use]
+    pub const fn is_final(self) -> bool {
+        matches!(self, MessageState::Delivered | MessageState::Failed)
+    }
+}
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::ToSql for MessageState {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        Ok(rusqlite::types::ToSqlOutput::from(self.as_str()))
+    }
+}
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::FromSql for MessageState {
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        let value = value.as_str()?;
+        Self::from_name(value).ok_or_else(|| {
+            rusqlite::types::FromSqlError::Other(format!("Invalid message state: {value}").into())
+        })
+    }
+}
+#[cfg(feature = "diesel")]
+impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for MessageState
+where
+    DB: diesel::backend::Backend,
+    str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        self.as_str().to_sql(out)
+    }
+}
+#[cfg(feature = "diesel")]
+impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for MessageState
+where
+    DB: diesel::backend::Backend,
+    String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        let value = String::from_sql(bytes)?;
+        Self::from_name(&value).ok_or_else(|| format!("Invalid message state: {value}").into())
+    }
+}
+impl From<crate::sms::SmsDeliveryReportStatusCategory> for MessageState {
+    fn from(value: crate::sms::SmsDeliveryReportStatusCategory) -> Self {
+        use crate::sms::SmsDeliveryReportStatusCategory;
+
+        match value {
+            SmsDeliveryReportStatusCategory::Sent => MessageState::Sent,
+            SmsDeliveryReportStatusCategory::Received => MessageState::Delivered,
+            SmsDeliveryReportStatusCategory::Retrying => MessageState::Retrying,
+            SmsDeliveryReportStatusCategory::Failed => MessageState::Failed,
+        }
+    }
+}
+
+/// A single state change in a message lifecycle.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct MessageTransition {
+    /// The state entered.
+    pub state: MessageState,
+
+    /// Unix timestamp in milliseconds when the state was entered.
+    pub at: u64,
+
+    /// The delivery report TP-Status that caused the transition, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u8>,
+}
+
+/// The folded state of a single outgoing message, built from its outgoing
+/// message and delivery report events, eg: for showing per-message timelines.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct MessageLifecycle {
+    /// The message this lifecycle is for.
+    pub message_id: i64,
+
+    /// Every state change, in the order they were applied.
+    pub transitions: Vec<MessageTransition>,
+}
+impl MessageLifecycle {
+    /// Create an empty lifecycle for a message.
+    #[must_use]
+    pub fn new(message_id: i64) -> Self {
+        Self {
+            message_id,
+            transitions: Vec::new(),
+        }
+    }
+
+    /// Fold a sequence of envelopes for any messages into the lifecycle for one message.
+    pub fn from_envelopes<'a>(
+        message_id: i64,
+        envelopes: impl IntoIterator<Item = &'a EventEnvelope>,
+    ) -> Self {
+        let mut lifecycle = Self::new(message_id);
+        for envelope in envelopes {
+            lifecycle.apply_envelope(envelope);
+        }
+        lifecycle
+    }
+
+    /// Apply an event that happened at a Unix timestamp in milliseconds.
+    /// Returns true if the event related to this message and changed its state.
+    /// Once the message is in a final state, later reports are ignored.
+    pub fn apply(&mut self, event: &Event, at: u64) -> bool {
+        let (state, status) = match event {
+            Event::OutgoingMessage { message, .. }
+                if message.message_id == Some(self.message_id) =>
+            {
+                if !self.transitions.is_empty() {
+                    return false;
+                }
+                (MessageState::Sending, None)
+            }
+            Event::DeliveryReport { message_id, report } if *message_id == self.message_id => (
+                MessageState::from(crate::sms::SmsDeliveryReportStatusCategory::from(report)),
+                Some(report.status),
+            ),
+            _ => return false,
+        };
+        if self.state().is_some_and(MessageState::is_final) {
+            return false;
+        }
+
+        self.transitions
+            .push(MessageTransition { state, at, status });
+        true
+    }
+
+    /// Apply an enveloped event, using its emit time.
+    pub fn apply_envelope(&mut self, envelope: &EventEnvelope) -> bool {
+        self.apply(&envelope.event, envelope.emitted_at)
+    }
+
+    /// Get the current state, or None if no events have been applied.
+    #[must_use]
+    pub fn state(&self) -> Option<MessageState> {
+        self.transitions.last().map(|transition| transition.state)
+    }
+
+    /// Get when the message was first seen, as a Unix timestamp in milliseconds.
+    #[must_use]
+    pub fn started_at(&self) -> Option<u64> {
+        self.transitions.first().map(|transition| transition.at)
+    }
+
+    /// Get when the message reached a final state, as a Unix timestamp in milliseconds.
+    #[must_use]
+    pub fn completed_at(&self) -> Option<u64> {
+        self.transitions
+            .last()
+            .filter(|transition| transition.state.is_final())
+            .map(|transition| transition.at)
+    }
+}
+
+/// How urgently an event should be brought to an operator's attention.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
+pub enum EventSeverity {
+    /// Routine activity.
+    Info,
+
+    /// Something may need attention soon.
+    Warning,
+
+    /// Something has failed and needs attention now.
+    Critical,
+}
+impl std::fmt::Display for EventSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            EventSeverity::Info => "Info",
+            EventSeverity::Warning => "Warning",
+            EventSeverity::Critical => "Critical",
+        })
+    }
+}
+
+impl Event {
+    /// Classify the event, so generic alerting sinks can route events without
+    /// knowing every variant. Eg: permanent delivery failures and modem faults are Critical.
+    #[must_use]
+    pub fn severity(&self) -> EventSeverity {
+        use crate::modem::ModemStatusUpdateState;
+        use crate::sms::SmsDeliveryReportStatusCategory;
+
+        match self {
+            Event::DeliveryReport { report, .. } => {
+                match SmsDeliveryReportStatusCategory::from(report) {
+                    SmsDeliveryReportStatusCategory::Failed => EventSeverity::Critical,
+                    SmsDeliveryReportStatusCategory::Retrying => EventSeverity::Warning,
+                    _ => EventSeverity::Info,
+                }
+            }
+            Event::ModemStatusUpdate { current, .. } => match current {
+                ModemStatusUpdateState::Offline => EventSeverity::Critical,
+                ModemStatusUpdateState::ShuttingDown => EventSeverity::Warning,
+                _ => EventSeverity::Info,
+            },
+            Event::SimStatusChange { current, .. } if !current.is_ready() => {
+                EventSeverity::Critical
+            }
+            Event::SignalStrengthUpdate { signal, .. } if !signal.is_known() => {
+                EventSeverity::Warning
+            }
+            Event::WebhookDeliveryFailed { .. } | Event::BatteryLow { .. } => {
+                EventSeverity::Warning
+            }
+            Event::StorageFull { status, .. } => {
+                if status.is_full() {
+                    EventSeverity::Critical
+                } else {
+                    EventSeverity::Warning
+                }
+            }
+
+            #[cfg(feature = "gnss")]
+            Event::GnssPowerStateUpdate {
+                current: crate::gnss::GnssPowerState::Error,
+                ..
+            } => EventSeverity::Warning,
+
+            _ => EventSeverity::Info,
+        }
+    }
+
+    /// Get the phone number an event relates to, if any.
+    /// This is None for incoming calls where the caller withheld their number.
+    #[must_use]
+    pub fn phone_number(&self) -> Option<&str> {
+        match self {
+            Event::IncomingMessage(message) | Event::OutgoingMessage { message, .. } => {
+                Some(&message.phone_number)
+            }
+            Event::DeliveryReport { report, .. } => Some(&report.phone_number),
+            Event::IncomingCall(call) => call.caller.phone_number.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Get the direction of a message or delivery report event.
+    #[must_use]
+    pub fn direction(&self) -> Option<MessageDirection> {
+        match self {
+            Event::IncomingMessage(_) => Some(MessageDirection::Incoming),
+            Event::OutgoingMessage { .. } | Event::DeliveryReport { .. } => {
+                Some(MessageDirection::Outgoing)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// An event borrowed from its serialized JSON, eg: for filtering or routing webhook
+/// payloads by kind and phone number without deserializing every field.
+/// The data is kept as raw JSON, and only parsed when it's inspected.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct EventRef<'a> {
+    /// The kind of event, `EventKind::Unknown` for types this version doesn't know.
+    #[serde(rename = "type")]
+    pub kind: EventKind,
+
+    /// The raw JSON event data.
+    #[serde(borrow)]
+    pub data: &'a serde_json::value::RawValue,
+}
+impl<'a> EventRef<'a> {
+    /// Borrow the message of an incoming or outgoing message event.
+    /// Returns None for other kinds of event.
+    ///
+    /// # Errors
+    /// Returns an error if the event data isn't a valid message.
+    pub fn message(&self) -> Result<Option<crate::sms::SmsMessageRef<'a>>, serde_json::Error> {
+        match self.kind {
+            EventKind::IncomingMessage | EventKind::OutgoingMessage => {
+                serde_json::from_str(self.data.get()).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Borrow the phone number the event relates to, if any.
+    /// This matches `Event::phone_number` for the same event.
+    ///
+    /// # Errors
+    /// Returns an error if the event data doesn't match its kind.
+    pub fn phone_number(&self) -> Result<Option<Cow<'a, str>>, serde_json::Error> {
+        let data = self.data.get();
+        Ok(match self.kind {
+            EventKind::IncomingMessage | EventKind::OutgoingMessage => {
+                Some(serde_json::from_str::<PhoneNumberRef>(data)?.phone_number)
+            }
+            EventKind::DeliveryReport => Some(
+                serde_json::from_str::<DeliveryReportRef>(data)?
+                    .report
+                    .phone_number,
+            ),
+            EventKind::IncomingCall => {
+                serde_json::from_str::<IncomingCallRef>(data)?
+                    .caller
+                    .phone_number
+            }
+            _ => None,
+        })
+    }
+}
+impl TryFrom<EventRef<'_>> for Event {
+    type Error = serde_json::Error;
+
+    /// Parse the event, failing for unknown kinds as their type name isn't kept.
+    fn try_from(value: EventRef<'_>) -> Result<Self, Self::Error> {
+        if value.kind == EventKind::Unknown {
+            return Err(serde::de::Error::custom("Unknown event type"));
+        }
+        serde_json::from_str(&serde_json::to_string(&value)?)
+    }
+}
+
+/// The borrowed phone number of a message or caller.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+struct PhoneNumberRef<'a> {
+    #[serde(borrow)]
+    phone_number: Cow<'a, str>,
+}
+
+/// The borrowed phone number of a delivery report event.
+#[derive(Deserialize)]
+struct DeliveryReportRef<'a> {
+    #[serde(borrow)]
+    report: PhoneNumberRef<'a>,
+}
+
+/// The borrowed phone number of an incoming call event, which is None if it was withheld.
+#[derive(Deserialize)]
+struct IncomingCallRef<'a> {
+    #[serde(borrow)]
+    caller: OptionalPhoneNumberRef<'a>,
+}
+
+/// The borrowed phone number of a caller.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+struct OptionalPhoneNumberRef<'a> {
+    #[serde(borrow, default)]
+    phone_number: Option<Cow<'a, str>>,
+}
+
+/// A stable key for partitioning events across stream partitions, eg: Kafka message keys.
+/// Events with the same key should be published to the same partition, so their order is kept.
+pub trait PartitionKey {
+    /// Get the partition key.
+    fn partition_key(&self) -> &str;
+}
+impl PartitionKey for Event {
+    /// Message, delivery report and call events are keyed by phone number, so each
+    /// conversation stays ordered. Webhook failures are keyed by webhook id, custom
+    /// events by name, and everything else by event kind.
+    fn partition_key(&self) -> &str {
+        if let Some(phone_number) = self.phone_number() {
+            return phone_number;
+        }
+        match self {
+            Event::WebhookDeliveryFailed { webhook_id, .. } => webhook_id,
+            Event::Custom { name, .. } => name,
+            _ => EventKind::from(self).as_str(),
+        }
+    }
+}
+impl PartitionKey for EventEnvelope {
+    fn partition_key(&self) -> &str {
+        self.event.partition_key()
+    }
+}
+#[cfg(feature = "cbor")]
+impl Event {
+    /// Encode the event as deterministic CBOR.
+    ///
+    /// # Errors
+    /// Returns an error if the event cannot be serialized.
+    pub fn to_cbor(&self) -> Result<Vec<u8>, crate::cbor::EncodeError> {
+        crate::cbor::to_vec(self)
+    }
+
+    /// Decode an event from CBOR.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid encoded event.
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, crate::cbor::DecodeError> {
+        crate::cbor::from_slice(bytes)
+    }
 }
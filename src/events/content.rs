@@ -0,0 +1,340 @@
+//! A value buffered in the serde data model rather than a specific format, so fields
+//! read out of order (eg: an event's data before its type) can be replayed later
+//! with the same values and errors as reading them in place.
+
+use std::marker::PhantomData;
+
+use serde::de::{
+    self, Deserialize, DeserializeSeed, Deserializer, IntoDeserializer, Unexpected, Visitor,
+    value::{MapDeserializer, SeqDeserializer},
+};
+
+/// Any value in the serde data model.
+#[derive(Debug, Clone)]
+pub(crate) enum Content {
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    U128(u128),
+    I128(i128),
+    F64(f64),
+    Char(char),
+    String(String),
+    Bytes(Vec<u8>),
+    None,
+    Some(Box<Content>),
+    Unit,
+    Newtype(Box<Content>),
+    Seq(Vec<Content>),
+    Map(Vec<(Content, Content)>),
+}
+impl Content {
+    /// Replay the content, reporting the human readable flag of the format it was read from.
+    pub(crate) fn into_deserializer<E>(self, human_readable: bool) -> ContentDeserializer<E> {
+        ContentDeserializer {
+            content: self,
+            human_readable,
+            error: PhantomData,
+        }
+    }
+
+    fn unexpected(&self) -> Unexpected<'_> {
+        match self {
+            Content::Bool(v) => Unexpected::Bool(*v),
+            Content::U64(v) => Unexpected::Unsigned(*v),
+            Content::I64(v) => Unexpected::Signed(*v),
+            Content::U128(_) | Content::I128(_) => Unexpected::Other("128-bit integer"),
+            Content::F64(v) => Unexpected::Float(*v),
+            Content::Char(v) => Unexpected::Char(*v),
+            Content::String(v) => Unexpected::Str(v),
+            Content::Bytes(v) => Unexpected::Bytes(v),
+            Content::None | Content::Some(_) => Unexpected::Option,
+            Content::Unit => Unexpected::Unit,
+            Content::Newtype(_) => Unexpected::NewtypeStruct,
+            Content::Seq(_) => Unexpected::Seq,
+            Content::Map(_) => Unexpected::Map,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Content {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+struct ContentVisitor;
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Content, E> {
+        Ok(Content::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Content, E> {
+        Ok(Content::I64(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Content, E> {
+        Ok(Content::I128(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Content, E> {
+        Ok(Content::U64(v))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Content, E> {
+        Ok(Content::U128(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Content, E> {
+        Ok(Content::F64(v))
+    }
+
+    fn visit_char<E: de::Error>(self, v: char) -> Result<Content, E> {
+        Ok(Content::Char(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Content, E> {
+        Ok(Content::String(v.to_owned()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Content, E> {
+        Ok(Content::String(v))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Content, E> {
+        Ok(Content::Bytes(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Content, E> {
+        Ok(Content::Bytes(v))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Content, E> {
+        Ok(Content::None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Content, D::Error> {
+        Content::deserialize(deserializer).map(|v| Content::Some(Box::new(v)))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Content, E> {
+        Ok(Content::Unit)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Content, D::Error> {
+        Content::deserialize(deserializer).map(|v| Content::Newtype(Box::new(v)))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Content, A::Error> {
+        let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(256));
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+        Ok(Content::Seq(elements))
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Content, A::Error> {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0).min(256));
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Content::Map(entries))
+    }
+}
+
+/// Replays buffered `Content` into any `Deserialize` type.
+pub(crate) struct ContentDeserializer<E> {
+    content: Content,
+    human_readable: bool,
+    error: PhantomData<E>,
+}
+impl<E: de::Error> IntoDeserializer<'_, E> for ContentDeserializer<E> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de, E: de::Error> Deserializer<'de> for ContentDeserializer<E> {
+    type Error = E;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        let human_readable = self.human_readable;
+        match self.content {
+            Content::Bool(v) => visitor.visit_bool(v),
+            Content::U64(v) => visitor.visit_u64(v),
+            Content::I64(v) => visitor.visit_i64(v),
+            Content::U128(v) => visitor.visit_u128(v),
+            Content::I128(v) => visitor.visit_i128(v),
+            Content::F64(v) => visitor.visit_f64(v),
+            Content::Char(v) => visitor.visit_char(v),
+            Content::String(v) => visitor.visit_string(v),
+            Content::Bytes(v) => visitor.visit_byte_buf(v),
+            Content::None => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some((*v).into_deserializer(human_readable)),
+            Content::Unit => visitor.visit_unit(),
+            Content::Newtype(v) => {
+                visitor.visit_newtype_struct((*v).into_deserializer(human_readable))
+            }
+            Content::Seq(elements) => {
+                let mut seq = SeqDeserializer::new(
+                    elements
+                        .into_iter()
+                        .map(|v| v.into_deserializer(human_readable)),
+                );
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Content::Map(entries) => {
+                let mut map = MapDeserializer::new(entries.into_iter().map(|(k, v)| {
+                    (
+                        k.into_deserializer(human_readable),
+                        v.into_deserializer(human_readable),
+                    )
+                }));
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some((*v).into_deserializer(self.human_readable)),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        match self.content {
+            Content::Newtype(v) => {
+                visitor.visit_newtype_struct((*v).into_deserializer(self.human_readable))
+            }
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        // Enums are buffered as either a variant name, or a map with a single entry
+        // from the variant name to its content.
+        let human_readable = self.human_readable;
+        let (variant, value) = match self.content {
+            Content::Map(mut entries) if entries.len() == 1 => {
+                let (variant, value) = entries.remove(0);
+                (variant, Some(value))
+            }
+            variant @ (Content::String(_) | Content::U64(_)) => (variant, None),
+            other => {
+                return Err(de::Error::invalid_type(other.unexpected(), &"an enum"));
+            }
+        };
+        visitor.visit_enum(EnumDeserializer {
+            variant: variant.into_deserializer(human_readable),
+            value: value.map(|v| v.into_deserializer(human_readable)),
+        })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.human_readable
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct EnumDeserializer<E> {
+    variant: ContentDeserializer<E>,
+    value: Option<ContentDeserializer<E>>,
+}
+impl<'de, E: de::Error> de::EnumAccess<'de> for EnumDeserializer<E> {
+    type Error = E;
+    type Variant = VariantDeserializer<E>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), E> {
+        let variant = seed.deserialize(self.variant)?;
+        Ok((variant, VariantDeserializer { value: self.value }))
+    }
+}
+
+struct VariantDeserializer<E> {
+    value: Option<ContentDeserializer<E>>,
+}
+impl<'de, E: de::Error> de::VariantAccess<'de> for VariantDeserializer<E> {
+    type Error = E;
+
+    fn unit_variant(self) -> Result<(), E> {
+        match self.value {
+            None
+            | Some(ContentDeserializer {
+                content: Content::Unit,
+                ..
+            }) => Ok(()),
+            Some(value) => Err(de::Error::invalid_type(
+                value.content.unexpected(),
+                &"a unit variant",
+            )),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, E> {
+        match self.value {
+            Some(value) => seed.deserialize(value),
+            None => Err(de::Error::invalid_type(
+                Unexpected::UnitVariant,
+                &"a newtype variant",
+            )),
+        }
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, E> {
+        match self.value {
+            Some(value) => Deserializer::deserialize_seq(value, visitor),
+            None => Err(de::Error::invalid_type(
+                Unexpected::UnitVariant,
+                &"a tuple variant",
+            )),
+        }
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        match self.value {
+            Some(value) => Deserializer::deserialize_map(value, visitor),
+            None => Err(de::Error::invalid_type(
+                Unexpected::UnitVariant,
+                &"a struct variant",
+            )),
+        }
+    }
+}
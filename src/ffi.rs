@@ -0,0 +1,415 @@
+//! C mirrors of the core types, with JSON (de)serialization entrypoints.
+//! The matching declarations are in `include/sms_types.h`. Link this crate
+//! through a `staticlib` or `cdylib` crate to export the symbols.
+//!
+//! Strings returned to C are owned by Rust and must be released with the
+//! matching `*_free` function, never with `free()`.
+
+// Exporting symbols and reading C pointers requires unsafe code.
+#![allow(unsafe_code)]
+
+use crate::events::{Event, EventKind};
+use crate::modem::ModemStatusUpdateState;
+use crate::sms::{SmsMessage, SmsPartialDeliveryReport};
+use std::ffi::{CStr, CString, c_char};
+use std::ptr;
+
+/// The result of an FFI call.
+#[repr(C)]
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum SmsResult {
+    /// The call succeeded.
+    Ok = 0,
+
+    /// A required pointer was null.
+    NullPointer = 1,
+
+    /// A string was not valid UTF-8.
+    InvalidUtf8 = 2,
+
+    /// The JSON could not be parsed into the type.
+    InvalidJson = 3,
+
+    /// The value could not be serialized.
+    SerializeFailed = 4,
+}
+
+/// C mirror of `SmsMessage`. Optional values are paired with a `has_*` flag.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CSmsMessage {
+    /// Unique identifier for the message.
+    pub message_id: i64,
+
+    /// Whether `message_id` is set.
+    pub has_message_id: bool,
+
+    /// The phone number associated with this message, NUL terminated.
+    pub phone_number: *mut c_char,
+
+    /// The actual text content of the message, NUL terminated.
+    pub message_content: *mut c_char,
+
+    /// Modem assigned reference number.
+    pub message_reference: u8,
+
+    /// Whether `message_reference` is set.
+    pub has_message_reference: bool,
+
+    /// Whether this message was sent (true) or received (false).
+    pub is_outgoing: bool,
+
+    /// Unix timestamp when the message was created.
+    pub created_at: u32,
+
+    /// Whether `created_at` is set.
+    pub has_created_at: bool,
+
+    /// Unix timestamp when the message was completed/delivered.
+    pub completed_at: u32,
+
+    /// Whether `completed_at` is set.
+    pub has_completed_at: bool,
+
+    /// Service message center delivery status.
+    pub status: u8,
+
+    /// Whether `status` is set.
+    pub has_status: bool,
+}
+impl CSmsMessage {
+    /// An empty message with null strings, safe to free.
+    const EMPTY: Self = Self {
+        message_id: 0,
+        has_message_id: false,
+        phone_number: ptr::null_mut(),
+        message_content: ptr::null_mut(),
+        message_reference: 0,
+        has_message_reference: false,
+        is_outgoing: false,
+        created_at: 0,
+        has_created_at: false,
+        completed_at: 0,
+        has_completed_at: false,
+        status: 0,
+        has_status: false,
+    };
+
+    /// Convert the C message back into an `SmsMessage`, copying its strings.
+    ///
+    /// # Safety
+    /// The string pointers must be null or point to valid NUL terminated strings.
+    ///
+    /// # Errors
+    /// Returns an error if a string is null or not valid UTF-8.
+    pub unsafe fn to_message(&self) -> Result<SmsMessage, SmsResult> {
+        // SAFETY: The caller guarantees the pointers are null or valid strings.
+        let (phone_number, message_content) = unsafe {
+            (
+                read_str(self.phone_number)?,
+                read_str(self.message_content)?,
+            )
+        };
+
+        Ok(SmsMessage {
+            message_id: self.has_message_id.then_some(self.message_id),
+            phone_number: phone_number.into(),
+            message_content: message_content.into(),
+            message_reference: self.has_message_reference.then_some(self.message_reference),
+            is_outgoing: self.is_outgoing,
+            created_at: self.has_created_at.then_some(self.created_at),
+            completed_at: self.has_completed_at.then_some(self.completed_at),
+            status: self.has_status.then_some(self.status),
+        })
+    }
+}
+impl From<&SmsMessage> for CSmsMessage {
+    fn from(value: &SmsMessage) -> Self {
+        Self {
+            message_id: value.message_id.unwrap_or_default(),
+            has_message_id: value.message_id.is_some(),
+            phone_number: to_c_string(&value.phone_number),
+            message_content: to_c_string(&value.message_content),
+            message_reference: value.message_reference.unwrap_or_default(),
+            has_message_reference: value.message_reference.is_some(),
+            is_outgoing: value.is_outgoing,
+            created_at: value.created_at.unwrap_or_default(),
+            has_created_at: value.created_at.is_some(),
+            completed_at: value.completed_at.unwrap_or_default(),
+            has_completed_at: value.completed_at.is_some(),
+            status: value.status.unwrap_or_default(),
+            has_status: value.status.is_some(),
+        }
+    }
+}
+
+/// C mirror of `Event::DeliveryReport`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CDeliveryReport {
+    /// The target `message_id` this delivery report applies to.
+    pub message_id: i64,
+
+    /// The phone number that sent back the delivery report, NUL terminated.
+    pub phone_number: *mut c_char,
+
+    /// The modem assigned message reference.
+    pub reference_id: u8,
+
+    /// The SMS TP-Status.
+    pub status: u8,
+}
+impl CDeliveryReport {
+    /// An empty report with a null string, safe to free.
+    const EMPTY: Self = Self {
+        message_id: 0,
+        phone_number: ptr::null_mut(),
+        reference_id: 0,
+        status: 0,
+    };
+}
+impl From<(i64, &SmsPartialDeliveryReport)> for CDeliveryReport {
+    fn from((message_id, report): (i64, &SmsPartialDeliveryReport)) -> Self {
+        Self {
+            message_id,
+            phone_number: to_c_string(&report.phone_number),
+            reference_id: report.reference_id,
+            status: report.status,
+        }
+    }
+}
+
+/// C mirror of `Event`. The `kind` selects which of the payload fields is set,
+/// every event also carries its full JSON representation for the remaining kinds.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CEvent {
+    /// The event kind, as its index in `EventKind::ALL` (see `SMS_EVENT_*` in the header).
+    pub kind: u8,
+
+    /// The message, set for incoming and outgoing message events.
+    pub message: CSmsMessage,
+
+    /// The websocket client id that sent an outgoing message.
+    pub origin_client_id: u64,
+
+    /// Whether `origin_client_id` is set.
+    pub has_origin_client_id: bool,
+
+    /// The delivery report, set for delivery report events.
+    pub delivery_report: CDeliveryReport,
+
+    /// The previous modem status for modem status update events (see `SMS_MODEM_*` in the header).
+    pub modem_previous: u8,
+
+    /// The current modem status for modem status update events.
+    pub modem_current: u8,
+
+    /// The full event as JSON, NUL terminated.
+    pub json: *mut c_char,
+}
+impl CEvent {
+    /// Convert an event into its C mirror, the strings must be released with `sms_event_free`.
+    ///
+    /// # Errors
+    /// Returns an error if the event cannot be serialized to JSON.
+    pub fn new(event: &Event) -> Result<Self, SmsResult> {
+        let json = serde_json::to_string(event).map_err(|_| SmsResult::SerializeFailed)?;
+        let mut out = Self {
+            kind: EventKind::from(event).index(),
+            message: CSmsMessage::EMPTY,
+            origin_client_id: 0,
+            has_origin_client_id: false,
+            delivery_report: CDeliveryReport::EMPTY,
+            modem_previous: 0,
+            modem_current: 0,
+            json: to_c_string(&json),
+        };
+
+        match event {
+            Event::IncomingMessage(message) => out.message = message.into(),
+            Event::OutgoingMessage {
+                message,
+                origin_client_id,
+            } => {
+                out.message = message.into();
+                out.origin_client_id = origin_client_id.unwrap_or_default();
+                out.has_origin_client_id = origin_client_id.is_some();
+            }
+            Event::DeliveryReport { message_id, report } => {
+                out.delivery_report = (*message_id, report).into();
+            }
+            Event::ModemStatusUpdate { previous, current } => {
+                out.modem_previous = modem_state_tag(previous);
+                out.modem_current = modem_state_tag(current);
+            }
+            _ => {}
+        }
+        Ok(out)
+    }
+}
+
+fn modem_state_tag(state: &ModemStatusUpdateState) -> u8 {
+    match state {
+        ModemStatusUpdateState::Startup => 0,
+        ModemStatusUpdateState::Online => 1,
+        ModemStatusUpdateState::ShuttingDown => 2,
+        ModemStatusUpdateState::Offline => 3,
+        ModemStatusUpdateState::Other(_) => u8::MAX,
+    }
+}
+
+/// Allocate a C string, dropping any interior NUL bytes.
+fn to_c_string(value: &str) -> *mut c_char {
+    CString::new(value.replace('\0', ""))
+        .unwrap_or_default()
+        .into_raw()
+}
+
+/// Copy a C string into an owned string.
+///
+/// # Safety
+/// The pointer must be null or point to a valid NUL terminated string.
+unsafe fn read_str(value: *const c_char) -> Result<String, SmsResult> {
+    if value.is_null() {
+        return Err(SmsResult::NullPointer);
+    }
+
+    // SAFETY: The caller guarantees the pointer is a valid NUL terminated string.
+    unsafe { CStr::from_ptr(value) }
+        .to_str()
+        .map(str::to_owned)
+        .map_err(|_| SmsResult::InvalidUtf8)
+}
+
+/// Release a string allocated by Rust and reset the pointer.
+///
+/// # Safety
+/// The pointer must be null or have been returned from this module.
+unsafe fn free_string(value: &mut *mut c_char) {
+    if !value.is_null() {
+        // SAFETY: The caller guarantees the string was allocated by `to_c_string`.
+        drop(unsafe { CString::from_raw(*value) });
+        *value = ptr::null_mut();
+    }
+}
+
+/// Parse an `SmsMessage` from JSON into `out`, which must be freed with `sms_message_free`.
+///
+/// # Safety
+/// `json` must be null or a valid NUL terminated string, and `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sms_message_from_json(
+    json: *const c_char,
+    out: *mut CSmsMessage,
+) -> SmsResult {
+    if out.is_null() {
+        return SmsResult::NullPointer;
+    }
+
+    // SAFETY: The caller guarantees `json` is null or a valid string.
+    let json = match unsafe { read_str(json) } {
+        Ok(json) => json,
+        Err(result) => return result,
+    };
+    match serde_json::from_str::<SmsMessage>(&json) {
+        Ok(message) => {
+            // SAFETY: The caller guarantees `out` is valid for writes.
+            unsafe { out.write(CSmsMessage::from(&message)) };
+            SmsResult::Ok
+        }
+        Err(_) => SmsResult::InvalidJson,
+    }
+}
+
+/// Serialize a C message to JSON, which must be freed with `sms_string_free`.
+/// Returns null if the message is invalid.
+///
+/// # Safety
+/// `message` must be null or point to a valid `CSmsMessage`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sms_message_to_json(message: *const CSmsMessage) -> *mut c_char {
+    // SAFETY: The caller guarantees `message` is null or valid.
+    let Some(message) = (unsafe { message.as_ref() }) else {
+        return ptr::null_mut();
+    };
+
+    // SAFETY: The caller guarantees the message strings are valid.
+    match unsafe { message.to_message() }.map(|message| serde_json::to_string(&message)) {
+        Ok(Ok(json)) => to_c_string(&json),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Release the strings of a message populated by this library.
+///
+/// # Safety
+/// `message` must be null or point to a message populated by this library.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sms_message_free(message: *mut CSmsMessage) {
+    // SAFETY: The caller guarantees `message` is null or valid.
+    if let Some(message) = unsafe { message.as_mut() } {
+        // SAFETY: The strings were allocated by `to_c_string`.
+        unsafe {
+            free_string(&mut message.phone_number);
+            free_string(&mut message.message_content);
+        }
+    }
+}
+
+/// Parse an `Event` from JSON into `out`, which must be freed with `sms_event_free`.
+///
+/// # Safety
+/// `json` must be null or a valid NUL terminated string, and `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sms_event_from_json(json: *const c_char, out: *mut CEvent) -> SmsResult {
+    if out.is_null() {
+        return SmsResult::NullPointer;
+    }
+
+    // SAFETY: The caller guarantees `json` is null or a valid string.
+    let json = match unsafe { read_str(json) } {
+        Ok(json) => json,
+        Err(result) => return result,
+    };
+    let Ok(event) = serde_json::from_str::<Event>(&json) else {
+        return SmsResult::InvalidJson;
+    };
+    match CEvent::new(&event) {
+        Ok(event) => {
+            // SAFETY: The caller guarantees `out` is valid for writes.
+            unsafe { out.write(event) };
+            SmsResult::Ok
+        }
+        Err(result) => result,
+    }
+}
+
+/// Release the strings of an event populated by this library.
+///
+/// # Safety
+/// `event` must be null or point to an event populated by this library.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sms_event_free(event: *mut CEvent) {
+    // SAFETY: The caller guarantees `event` is null or valid.
+    if let Some(event) = unsafe { event.as_mut() } {
+        // SAFETY: The strings were allocated by `to_c_string`.
+        unsafe {
+            sms_message_free(&raw mut event.message);
+            free_string(&mut event.delivery_report.phone_number);
+            free_string(&mut event.json);
+        }
+    }
+}
+
+/// Release a string returned by this library.
+///
+/// # Safety
+/// `value` must be null or a string returned by this library, and not already freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sms_string_free(value: *mut c_char) {
+    let mut value = value;
+
+    // SAFETY: The caller guarantees the string was returned by this library.
+    unsafe { free_string(&mut value) };
+}
@@ -0,0 +1,206 @@
+//! Realistic fake values for demo modes and load tests, using `Faker` from the `fake` crate.
+//!
+//! eg: `let message: SmsMessage = Faker.fake();`
+
+use crate::events::Event;
+use crate::modem::{BatteryLevel, ModemStatusUpdateState, SignalStrength};
+use crate::sms::{SmsMessage, SmsPartialDeliveryReport};
+use fake::faker::lorem::en::Sentence;
+use fake::{Dummy, Fake, Faker, RngExt};
+
+/// Network operator names used for fake device info.
+#[cfg(feature = "http")]
+const OPERATORS: [&str; 4] = ["Vodafone UK", "EE", "O2 - UK", "3 UK"];
+
+/// Get a Unix timestamp in seconds within the last 30 days.
+fn recent_unix_seconds<R: RngExt + ?Sized>(rng: &mut R) -> u32 {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| {
+            u32::try_from(duration.as_secs()).unwrap_or(u32::MAX)
+        });
+    now.saturating_sub(rng.random_range(0..30 * 86_400))
+}
+
+/// Get a UK mobile number in E.164 format, eg: "+447700900123".
+fn phone_number<R: RngExt + ?Sized>(rng: &mut R) -> String {
+    format!("+447{:09}", rng.random_range(0..1_000_000_000u32))
+}
+
+impl Dummy<Faker> for SmsMessage {
+    /// Outgoing messages have a reference, and most have been delivered.
+    fn dummy_with_rng<R: RngExt + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        let is_outgoing = rng.random_bool(0.5);
+        let created_at = recent_unix_seconds(rng);
+        let delivered = is_outgoing && rng.random_bool(0.8);
+
+        Self {
+            message_id: Some(rng.random_range(1..1_000_000)),
+            phone_number: phone_number(rng).into(),
+            message_content: Sentence(3..12).fake_with_rng::<String, _>(rng).into(),
+            message_reference: is_outgoing.then(|| rng.random()),
+            is_outgoing,
+            created_at: Some(created_at),
+            completed_at: delivered.then(|| created_at + rng.random_range(1..120)),
+            status: delivered.then_some(0),
+        }
+    }
+}
+
+impl Dummy<Faker> for SignalStrength {
+    fn dummy_with_rng<R: RngExt + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        Self {
+            rssi: rng.random_range(5..=31),
+            ber: rng.random_range(0..=2),
+        }
+    }
+}
+
+impl Dummy<Faker> for BatteryLevel {
+    fn dummy_with_rng<R: RngExt + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        let charge = rng.random_range(5..=100);
+        Self {
+            status: rng.random_range(0..=1),
+            charge,
+            voltage: 3.4 + f32::from(charge) * 0.008,
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl Dummy<Faker> for crate::http::HttpModemNetworkStatusResponse {
+    /// Registered on the home network or roaming, on 4G.
+    fn dummy_with_rng<R: RngExt + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        Self {
+            registration: if rng.random_bool(0.9) { 1 } else { 5 },
+            technology: 7,
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl Dummy<Faker> for crate::http::HttpModemNetworkOperatorResponse {
+    fn dummy_with_rng<R: RngExt + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        Self {
+            status: 0,
+            format: 0,
+            operator: OPERATORS[rng.random_range(0..OPERATORS.len())].to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl Dummy<Faker> for crate::http::HttpSmsDeviceInfoResponse {
+    fn dummy_with_rng<R: RngExt + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+        let network_operator: crate::http::HttpModemNetworkOperatorResponse =
+            config.fake_with_rng(rng);
+        Self {
+            version: format!(
+                "{}.{}.{}",
+                rng.random_range(1..3),
+                rng.random_range(0..10),
+                rng.random_range(0..20)
+            ),
+            phone_number: Some(phone_number(rng)),
+            service_provider: Some(network_operator.operator.clone()),
+            network_operator: Some(network_operator),
+            network_status: Some(config.fake_with_rng(rng)),
+            battery: Some(config.fake_with_rng(rng)),
+            signal: Some(config.fake_with_rng(rng)),
+        }
+    }
+}
+
+#[cfg(feature = "gnss")]
+impl Dummy<Faker> for crate::gnss::PositionReport {
+    /// A 3D fix within the UK, received in the last 30 days.
+    fn dummy_with_rng<R: RngExt + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        let millis = u64::from(recent_unix_seconds(rng)) * 1000;
+        let (year, month, day, hour, minute, second, millis) =
+            crate::timestamp::civil_from_millis(millis);
+        let hdop: f32 = rng.random_range(0.6..2.5);
+
+        Self {
+            run_status: true,
+            fix_status: true,
+            utc_time: format!(
+                "{year:04}{month:02}{day:02}{hour:02}{minute:02}{second:02}.{millis:03}"
+            ),
+            latitude: Some(rng.random_range(50.0..58.5)),
+            longitude: Some(rng.random_range(-5.5..1.7)),
+            msl_altitude: Some(rng.random_range(0.0..300.0)),
+            ground_speed: Some(rng.random_range(0.0..30.0)),
+            ground_course: Some(rng.random_range(0.0..360.0)),
+            fix_mode: crate::gnss::FixStatus::Fix3D,
+            hdop: Some(hdop),
+            pdop: Some(hdop * 1.4),
+            vdop: Some(hdop * 1.1),
+            gps_in_view: Some(rng.random_range(6..14)),
+            gnss_used: Some(rng.random_range(4..12)),
+            glonass_in_view: Some(rng.random_range(2..10)),
+            satellites: Vec::new(),
+        }
+    }
+}
+
+impl Dummy<Faker> for Event {
+    /// One of the common events: messages, delivery reports, modem updates and positions.
+    fn dummy_with_rng<R: RngExt + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+        let kinds = if cfg!(feature = "gnss") { 7 } else { 6 };
+        match rng.random_range(0..kinds) {
+            0 => Event::IncomingMessage(SmsMessage {
+                is_outgoing: false,
+                message_reference: None,
+                completed_at: None,
+                status: None,
+                ..config.fake_with_rng(rng)
+            }),
+            1 => Event::OutgoingMessage {
+                message: SmsMessage {
+                    is_outgoing: true,
+                    message_reference: Some(rng.random()),
+                    completed_at: None,
+                    status: None,
+                    ..config.fake_with_rng(rng)
+                },
+                origin_client_id: None,
+            },
+            2 => Event::DeliveryReport {
+                message_id: rng.random_range(1..1_000_000),
+                report: SmsPartialDeliveryReport {
+                    phone_number: phone_number(rng),
+                    reference_id: rng.random(),
+                    status: if rng.random_bool(0.8) { 0x00 } else { 0x41 },
+                },
+            },
+            3 => Event::ModemStatusUpdate {
+                previous: ModemStatusUpdateState::Startup,
+                current: ModemStatusUpdateState::Online,
+            },
+            4 => Event::SignalStrengthUpdate {
+                signal: config.fake_with_rng(rng),
+                sampled_at: recent_unix_seconds(rng),
+            },
+            5 => Event::BatteryLow {
+                battery: BatteryLevel {
+                    status: 0,
+                    charge: rng.random_range(5..=20),
+                    voltage: 3.5,
+                },
+                threshold: 20,
+            },
+            #[cfg(feature = "gnss")]
+            _ => {
+                let report: crate::gnss::PositionReport = config.fake_with_rng(rng);
+                let received_at = report.utc_time_millis().unwrap_or_default();
+                Event::GnssPositionReport(crate::gnss::TimedPosition {
+                    report,
+                    received_at,
+                    coarse: None,
+                })
+            }
+            #[cfg(not(feature = "gnss"))]
+            _ => unreachable!(),
+        }
+    }
+}
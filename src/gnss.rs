@@ -1,9 +1,259 @@
 //! GNSS position report types.
 
+use crate::timestamp::Timestamp;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 use serde::{Deserialize, Serialize};
 
-/// GNSS (Global Navigation Satellite System) fix status.
+mod batch;
+mod coarse;
+mod coordinate;
+mod filter;
+pub mod geofence;
+mod last_known;
+mod movement;
+mod odometer;
+mod track;
+
+#[cfg(feature = "geojson")]
+mod geojson;
+#[cfg(feature = "gpx")]
+mod gpx;
+#[cfg(feature = "geo")]
+mod utm;
+
+pub use batch::{ALTITUDE_SCALE, COORDINATE_SCALE, PositionBatch, PositionDelta, PositionSample};
+pub use coarse::CoarseLocation;
+pub use coordinate::{Coordinate, CoordinateAxis, Dms, Hemisphere};
+pub use filter::PositionFilter;
+#[cfg(feature = "gpx")]
+pub use gpx::positions_to_gpx;
+pub use last_known::LastKnownPosition;
+pub use movement::{MovementAlert, MovementAlertConfig, MovementThreshold};
+pub use odometer::Odometer;
+pub use track::{BoundingBox, Track};
+#[cfg(feature = "geo")]
+pub use utm::Utm;
+
+/// Mean radius of the Earth in meters.
+pub const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// A latitude and longitude pair in decimal degrees.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct GeoPoint {
+    /// Latitude in decimal degrees.
+    pub latitude: f64,
+
+    /// Longitude in decimal degrees.
+    pub longitude: f64,
+}
+impl GeoPoint {
+    /// Create a new point from decimal degrees.
+    #[must_use]
+    pub const fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Get the great-circle distance in meters to another point, using the haversine formula.
+    #[must_use]
+    pub fn distance_to(&self, other: &GeoPoint) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.longitude - self.longitude).to_radians();
+
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS * a.sqrt().atan2((1.0 - a).sqrt())
+    }
+
+    /// Get the initial bearing in degrees (0 - 360, clockwise from north) to another point.
+    #[must_use]
+    pub fn bearing_to(&self, other: &GeoPoint) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let d_lon = (other.longitude - self.longitude).to_radians();
+
+        let y = d_lon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * d_lon.cos();
+        rem_euclid(y.atan2(x).to_degrees(), 360.0)
+    }
+
+    /// Get the point reached by travelling a distance in meters along an initial bearing in degrees.
+    #[must_use]
+    pub fn destination(&self, bearing: f64, distance: f64) -> GeoPoint {
+        let lat1 = self.latitude.to_radians();
+        let lon1 = self.longitude.to_radians();
+        let bearing = bearing.to_radians();
+        let angular = distance / EARTH_RADIUS;
+
+        let lat2 = (lat1.sin() * angular.cos() + lat1.cos() * angular.sin() * bearing.cos()).asin();
+        let lon2 = lon1
+            + (bearing.sin() * angular.sin() * lat1.cos())
+                .atan2(angular.cos() - lat1.sin() * lat2.sin());
+        GeoPoint::new(
+            lat2.to_degrees(),
+            rem_euclid(lon2.to_degrees() + 540.0, 360.0) - 180.0,
+        )
+    }
+}
+
+/// GNSS receiver power and acquisition state.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
+pub enum GnssPowerState {
+    /// The receiver is powered off.
+    Off,
+
+    /// The receiver is powered on and searching for a fix.
+    Acquiring,
+
+    /// The receiver has a fix and is tracking satellites.
+    Tracking,
+
+    /// The receiver failed to power on or stopped responding.
+    Error,
+}
+impl core::fmt::Display for GnssPowerState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GnssPowerState::Off => write!(f, "Off"),
+            GnssPowerState::Acquiring => write!(f, "Acquiring"),
+            GnssPowerState::Tracking => write!(f, "Tracking"),
+            GnssPowerState::Error => write!(f, "Error"),
+        }
+    }
+}
+
+/// How the GNSS receiver was started, which affects the time to first fix.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
+pub enum GnssStartType {
+    /// No valid almanac, ephemeris or time, eg: first power on or after moving far.
+    Cold,
+
+    /// Valid almanac and time but no current ephemeris.
+    Warm,
+
+    /// Valid almanac, ephemeris and time, eg: shortly after a power off.
+    Hot,
+}
+impl core::fmt::Display for GnssStartType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            GnssStartType::Cold => "Cold",
+            GnssStartType::Warm => "Warm",
+            GnssStartType::Hot => "Hot",
+        })
+    }
+}
+
+/// Time to first fix metrics, reported when a fix is first acquired after power on.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct FixMetrics {
+    /// How the receiver was started.
+    pub start_type: GnssStartType,
+
+    /// Time to first fix in milliseconds.
+    pub time_to_first_fix: u64,
+
+    /// The amount of satellites used in the first fix.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub satellites_used: Option<u8>,
+
+    /// HDOP at the first fix.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hdop: Option<f32>,
+
+    /// PDOP at the first fix.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pdop: Option<f32>,
+
+    /// VDOP at the first fix.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vdop: Option<f32>,
+
+    /// Unix timestamp in milliseconds when the fix was acquired.
+    pub acquired_at: u64,
+}
+impl FixMetrics {
+    /// Create metrics from the first position with a fix and when the receiver
+    /// was powered on, as a Unix timestamp in milliseconds.
+    #[must_use]
+    pub fn from_first_fix(
+        start_type: GnssStartType,
+        powered_on_at: u64,
+        fix: &TimedPosition,
+    ) -> Self {
+        Self {
+            start_type,
+            time_to_first_fix: fix.received_at.saturating_sub(powered_on_at),
+            satellites_used: fix.report.gnss_used,
+            hdop: fix.report.hdop,
+            pdop: fix.report.pdop,
+            vdop: fix.report.vdop,
+            acquired_at: fix.received_at,
+        }
+    }
+
+    /// Get when the fix was acquired.
+    #[must_use]
+    pub const fn acquired_timestamp(&self) -> Timestamp {
+        Timestamp::from_unix_millis(self.acquired_at)
+    }
+}
+
+/// GNSS (Global Navigation Satellite System) fix status, ordered by fix quality from `Unknown` to `Fix3D`.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
 pub enum FixStatus {
     /// GNSS fix status is unknown.
     Unknown,
@@ -17,6 +267,16 @@ pub enum FixStatus {
     /// 3D GNSS fix (latitude, longitude, and altitude).
     Fix3D,
 }
+impl core::fmt::Display for FixStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            FixStatus::Unknown => "Unknown",
+            FixStatus::NotFix => "NotFix",
+            FixStatus::Fix2D => "Fix2D",
+            FixStatus::Fix3D => "Fix3D",
+        })
+    }
+}
 impl TryFrom<&str> for FixStatus {
     type Error = String;
 
@@ -30,6 +290,19 @@ impl TryFrom<&str> for FixStatus {
         }
     }
 }
+impl core::str::FromStr for FixStatus {
+    type Err = String;
+
+    /// Parse a `FixStatus` from its displayed name, or the modem's status text.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "NotFix" => Ok(FixStatus::NotFix),
+            "Fix2D" => Ok(FixStatus::Fix2D),
+            "Fix3D" => Ok(FixStatus::Fix3D),
+            _ => FixStatus::try_from(s),
+        }
+    }
+}
 impl From<u8> for FixStatus {
     fn from(value: u8) -> Self {
         match value {
@@ -41,8 +314,319 @@ impl From<u8> for FixStatus {
     }
 }
 
+/// Units used to display a speed.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub enum SpeedUnit {
+    /// Meters per second, as reported by the modem.
+    #[default]
+    MetersPerSecond,
+
+    /// Kilometers per hour.
+    KilometersPerHour,
+
+    /// Miles per hour.
+    MilesPerHour,
+
+    /// Nautical miles per hour.
+    Knots,
+}
+impl SpeedUnit {
+    /// Convert a speed in meters per second into this unit.
+    #[must_use]
+    pub fn convert(self, mps: f64) -> f64 {
+        match self {
+            SpeedUnit::MetersPerSecond => mps,
+            SpeedUnit::KilometersPerHour => mps * 3.6,
+            SpeedUnit::MilesPerHour => mps * 3600.0 / 1609.344,
+            SpeedUnit::Knots => mps * 3600.0 / 1852.0,
+        }
+    }
+}
+impl core::fmt::Display for SpeedUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            SpeedUnit::MetersPerSecond => "m/s",
+            SpeedUnit::KilometersPerHour => "km/h",
+            SpeedUnit::MilesPerHour => "mph",
+            SpeedUnit::Knots => "kn",
+        })
+    }
+}
+
+/// Units used to display a distance or altitude.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub enum DistanceUnit {
+    /// Meters, as reported by the modem.
+    #[default]
+    Meters,
+
+    /// Kilometers.
+    Kilometers,
+
+    /// Feet.
+    Feet,
+
+    /// Statute miles.
+    Miles,
+
+    /// Nautical miles.
+    NauticalMiles,
+}
+impl DistanceUnit {
+    /// Convert a distance in meters into this unit.
+    #[must_use]
+    pub fn convert(self, meters: f64) -> f64 {
+        match self {
+            DistanceUnit::Meters => meters,
+            DistanceUnit::Kilometers => meters / 1000.0,
+            DistanceUnit::Feet => meters / 0.3048,
+            DistanceUnit::Miles => meters / 1609.344,
+            DistanceUnit::NauticalMiles => meters / 1852.0,
+        }
+    }
+}
+impl core::fmt::Display for DistanceUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            DistanceUnit::Meters => "m",
+            DistanceUnit::Kilometers => "km",
+            DistanceUnit::Feet => "ft",
+            DistanceUnit::Miles => "mi",
+            DistanceUnit::NauticalMiles => "nmi",
+        })
+    }
+}
+
+/// GNSS satellite constellation.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
+pub enum Constellation {
+    /// United States GPS.
+    Gps,
+
+    /// Russian GLONASS.
+    Glonass,
+
+    /// European Galileo.
+    Galileo,
+
+    /// Chinese `BeiDou`.
+    BeiDou,
+
+    /// Japanese QZSS.
+    Qzss,
+
+    /// Satellite-based augmentation systems (WAAS, EGNOS, etc).
+    Sbas,
+
+    /// Unknown constellation, or a constellation this version doesn't know.
+    #[serde(other)]
+    Unknown,
+}
+impl core::fmt::Display for Constellation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Constellation::Gps => "Gps",
+            Constellation::Glonass => "Glonass",
+            Constellation::Galileo => "Galileo",
+            Constellation::BeiDou => "BeiDou",
+            Constellation::Qzss => "Qzss",
+            Constellation::Sbas => "Sbas",
+            Constellation::Unknown => "Unknown",
+        })
+    }
+}
+impl Constellation {
+    /// Get the constellation from an NMEA talker id, eg: `GP` from `$GPGSV`.
+    #[must_use]
+    pub fn from_talker_id(talker_id: &str) -> Self {
+        match talker_id {
+            "GP" => Constellation::Gps,
+            "GL" => Constellation::Glonass,
+            "GA" => Constellation::Galileo,
+            "GB" | "BD" => Constellation::BeiDou,
+            "GQ" | "QZ" => Constellation::Qzss,
+            _ => Constellation::Unknown,
+        }
+    }
+}
+
+/// GNSS receiver configuration.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct GnssConfig {
+    /// Whether the GNSS receiver is powered on.
+    pub enabled: bool,
+
+    /// How often position reports are read from the modem, in seconds.
+    pub report_interval: u32,
+
+    /// The satellite constellations used for fixes.
+    pub constellations: Vec<Constellation>,
+
+    /// The minimum amount of satellites used in a fix for it to be reported.
+    pub min_satellites: u8,
+}
+impl Default for GnssConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            report_interval: 30,
+            constellations: vec![Constellation::Gps, Constellation::Glonass],
+            min_satellites: 4,
+        }
+    }
+}
+impl crate::validate::Validate for GnssConfig {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        use crate::validate::ValidationError;
+
+        if self.report_interval == 0 {
+            return Err(ValidationError::new(
+                "report_interval",
+                "must be greater than zero",
+            ));
+        }
+        if self.constellations.is_empty() {
+            return Err(ValidationError::new(
+                "constellations",
+                "must include at least one constellation",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Details of a single satellite in view, as reported in NMEA GSV sentences.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct SatelliteInfo {
+    /// The constellation the satellite belongs to.
+    pub constellation: Constellation,
+
+    /// Satellite PRN (pseudo-random noise) number.
+    pub prn: u16,
+
+    /// Signal to noise ratio in dB-Hz, None if the satellite is not being tracked.
+    pub snr: Option<u8>,
+
+    /// Elevation above the horizon in degrees (0 - 90).
+    pub elevation: Option<u8>,
+
+    /// Azimuth in degrees from true north (0 - 359).
+    pub azimuth: Option<u16>,
+
+    /// Whether the satellite is used in the current fix.
+    pub used_in_fix: bool,
+}
+
+/// User equivalent range error in meters, a typical single frequency receiver ranging error.
+const UERE: f64 = 5.0;
+
+/// How much an accuracy estimate can be trusted.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
+pub enum AccuracyConfidence {
+    /// Good geometry with plenty of satellites.
+    High,
+
+    /// Usable, but the error may be larger than estimated.
+    Medium,
+
+    /// Poor geometry or few satellites, the position may be well off.
+    Low,
+}
+impl core::fmt::Display for AccuracyConfidence {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            AccuracyConfidence::High => "High",
+            AccuracyConfidence::Medium => "Medium",
+            AccuracyConfidence::Low => "Low",
+        })
+    }
+}
+
+/// Approximate position error derived from the dilution of precision values.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct AccuracyEstimate {
+    /// Approximate horizontal error radius in meters.
+    pub horizontal: f64,
+
+    /// Approximate vertical error in meters, None without a 3D fix.
+    pub vertical: Option<f64>,
+
+    /// How much the estimate can be trusted.
+    pub confidence: AccuracyConfidence,
+}
+
 /// Represents a GNSS position report with optional fields for satellite info.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub struct PositionReport {
     /// Indicates whether the GNSS receiver is currently running.
     pub run_status: bool,
@@ -50,7 +634,8 @@ pub struct PositionReport {
     /// Whether a valid fix has been obtained.
     pub fix_status: bool,
 
-    /// UTC time of the position report in ISO 8601 format.
+    /// UTC time of the position report, as sent by the modem in `yyyyMMddhhmmss.sss` format.
+    /// Use `utc_time_millis` to get it as a Unix timestamp.
     pub utc_time: String,
 
     /// Latitude in decimal degrees.
@@ -88,49 +673,448 @@ pub struct PositionReport {
 
     /// Number of GLONASS satellites in view.
     pub glonass_in_view: Option<u8>,
+
+    /// Details of individual satellites in view, if they were requested from the modem.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub satellites: Vec<SatelliteInfo>,
 }
-impl TryFrom<Vec<&str>> for PositionReport {
-    type Error = String;
+impl PositionReport {
+    /// Get the reported position as a point, if both latitude and longitude are present.
+    #[must_use]
+    pub fn point(&self) -> Option<GeoPoint> {
+        Some(GeoPoint::new(self.latitude?, self.longitude?))
+    }
 
-    fn try_from(fields: Vec<&str>) -> Result<Self, Self::Error> {
-        if fields.len() < 15 {
-            return Err(format!(
-                "Insufficient GNSS data fields got {}",
-                fields.len()
-            ));
+    /// Get the great-circle distance in meters to another report.
+    /// Returns None if either report has no position.
+    #[must_use]
+    pub fn distance_to(&self, other: &PositionReport) -> Option<f64> {
+        Some(self.point()?.distance_to(&other.point()?))
+    }
+
+    /// Get the initial bearing in degrees (0 - 360, clockwise from north) to another report.
+    /// Returns None if either report has no position.
+    #[must_use]
+    pub fn bearing_to(&self, other: &PositionReport) -> Option<f64> {
+        Some(self.point()?.bearing_to(&other.point()?))
+    }
+
+    /// Estimate the position error in meters from the HDOP/VDOP and the amount
+    /// of satellites used. Returns None without a fix or HDOP value.
+    #[must_use]
+    pub fn accuracy_estimate(&self) -> Option<AccuracyEstimate> {
+        if !self.fix_status {
+            return None;
+        }
+        let hdop = f64::from(self.hdop?);
+        let vertical = match self.fix_mode {
+            FixStatus::Fix3D => self.vdop.map(|vdop| f64::from(vdop) * UERE),
+            _ => None,
+        };
+
+        let satellites = self.gnss_used.unwrap_or(0);
+        let confidence = if hdop <= 2.0 && satellites >= 6 {
+            AccuracyConfidence::High
+        } else if hdop <= 5.0 && satellites >= 4 {
+            AccuracyConfidence::Medium
+        } else {
+            AccuracyConfidence::Low
+        };
+
+        Some(AccuracyEstimate {
+            horizontal: hdop * UERE,
+            vertical,
+            confidence,
+        })
+    }
+
+    /// Get the ground speed in kilometers per hour.
+    #[must_use]
+    pub fn ground_speed_kmh(&self) -> Option<f64> {
+        self.ground_speed_in(SpeedUnit::KilometersPerHour)
+    }
+
+    /// Get the ground speed in knots.
+    #[must_use]
+    pub fn ground_speed_knots(&self) -> Option<f64> {
+        self.ground_speed_in(SpeedUnit::Knots)
+    }
+
+    /// Get the ground speed in the given unit.
+    #[must_use]
+    pub fn ground_speed_in(&self, unit: SpeedUnit) -> Option<f64> {
+        self.ground_speed.map(|mps| unit.convert(f64::from(mps)))
+    }
+
+    /// Get the mean sea level altitude in feet.
+    #[must_use]
+    pub fn msl_altitude_feet(&self) -> Option<f64> {
+        self.msl_altitude_in(DistanceUnit::Feet)
+    }
+
+    /// Get the mean sea level altitude in the given unit.
+    #[must_use]
+    pub fn msl_altitude_in(&self, unit: DistanceUnit) -> Option<f64> {
+        self.msl_altitude.map(|meters| unit.convert(meters))
+    }
+
+    /// Display the report with speed and altitude converted into the given units.
+    #[must_use]
+    pub fn display_with(
+        &self,
+        speed_unit: SpeedUnit,
+        distance_unit: DistanceUnit,
+    ) -> PositionReportDisplay<'_> {
+        PositionReportDisplay {
+            report: self,
+            speed_unit,
+            distance_unit,
+        }
+    }
+
+    /// Parse the modem UTC time into Unix milliseconds.
+    /// Returns None if the time is missing or invalid, eg: before a fix is obtained.
+    #[must_use]
+    pub fn utc_time_millis(&self) -> Option<u64> {
+        parse_utc_time_millis(&self.utc_time)
+    }
+
+    /// Parse the modem UTC time as a `Timestamp`.
+    #[must_use]
+    pub fn utc_timestamp(&self) -> Option<Timestamp> {
+        self.utc_time_millis().map(Timestamp::from_unix_millis)
+    }
+
+    /// Parse the modem UTC time as `SystemTime`.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn utc_system_time(&self) -> Option<std::time::SystemTime> {
+        self.utc_timestamp().map(Into::into)
+    }
+}
+
+/// Get the least non-negative remainder of `value` divided by `modulus`.
+/// Equivalent to `f64::rem_euclid`, which is only available with `std`.
+pub(crate) fn rem_euclid(value: f64, modulus: f64) -> f64 {
+    let remainder = value % modulus;
+    if remainder < 0.0 {
+        remainder + modulus.abs()
+    } else {
+        remainder
+    }
+}
+
+/// Get the distance in meters from `point` to the segment between `start` and `end`,
+/// using a local flat projection around `point`.
+pub(crate) fn segment_distance(start: &GeoPoint, end: &GeoPoint, point: &GeoPoint) -> f64 {
+    let project = |p: &GeoPoint| {
+        (
+            (p.longitude - point.longitude).to_radians()
+                * point.latitude.to_radians().cos()
+                * EARTH_RADIUS,
+            (p.latitude - point.latitude).to_radians() * EARTH_RADIUS,
+        )
+    };
+    let (a, b) = (project(start), project(end));
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length = dx * dx + dy * dy;
+    let t = if length == 0.0 {
+        0.0
+    } else {
+        (-(a.0 * dx + a.1 * dy) / length).clamp(0.0, 1.0)
+    };
+    (a.0 + t * dx).hypot(a.1 + t * dy)
+}
+
+/// Parse a `yyyyMMddhhmmss.sss` UTC time into Unix milliseconds.
+fn parse_utc_time_millis(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let (datetime, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if datetime.len() != 14 || !value.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+        return None;
+    }
+
+    let field = |range: core::ops::Range<usize>| datetime[range].parse::<u64>().ok();
+    let (year, month, day) = (field(0..4)?, field(4..6)?, field(6..8)?);
+    let (hour, minute, second) = (field(8..10)?, field(10..12)?, field(12..14)?);
+    if year < 1970 || !(1..=12).contains(&month) {
+        return None;
+    }
+    if !(1..=days_in_month(year, month)).contains(&day) {
+        return None;
+    }
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    // Milliseconds, padded or truncated to 3 digits.
+    let millis = if fraction.is_empty() {
+        0
+    } else {
+        format!("{fraction:0<3}")[..3].parse::<u64>().ok()?
+    };
+
+    // Days since the Unix epoch, from: https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let (y, m) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = y / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * m + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    Some((((days * 24 + hour) * 60 + minute) * 60 + second) * 1000 + millis)
+}
+
+/// Get the amount of days in a month (1 to 12) of a year, accounting for leap years.
+fn days_in_month(year: u64, month: u64) -> u64 {
+    let leap = year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+    match month {
+        2 => 28 + u64::from(leap),
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl PositionReport {
+    /// The minimum amount of fields required to parse a report (run and fix status).
+    pub const MIN_FIELDS: usize = 2;
+
+    /// Parse a report from its comma separated `+CGNSINF` fields.
+    /// Missing trailing fields (from older firmware) and empty fields are treated
+    /// as unset, and any extra trailing fields are ignored.
+    ///
+    /// # Errors
+    /// Returns an error if there are too few fields, or a present field is invalid.
+    pub fn from_fields(fields: &[&str]) -> Result<Self, GnssParseError> {
+        if fields.len() < Self::MIN_FIELDS {
+            return Err(GnssParseError::InsufficientFields {
+                expected: Self::MIN_FIELDS,
+                got: fields.len(),
+            });
         }
 
         // Based on: https://simcom.ee/documents/SIM868/SIM868_GNSS_Application%20Note_V1.00.pdf (2.3)
         Ok(Self {
-            run_status: fields[0] == "1",
-            fix_status: fields[1] == "1",
-            utc_time: fields[2].to_string(),
-            latitude: fields[3].parse().ok(),
-            longitude: fields[4].parse().ok(),
-            msl_altitude: fields[5].parse().ok(),
-            ground_speed: fields[6].parse().ok(),
-            ground_course: fields[7].parse().ok(),
-            fix_mode: FixStatus::from(fields[8].parse::<u8>().unwrap_or(0)),
+            run_status: parse_flag(fields, 0, "run_status")?,
+            fix_status: parse_flag(fields, 1, "fix_status")?,
+            utc_time: fields.get(2).map_or("", |v| v.trim()).to_string(),
+            latitude: parse_field(fields, 3, "latitude")?,
+            longitude: parse_field(fields, 4, "longitude")?,
+            msl_altitude: parse_field(fields, 5, "msl_altitude")?,
+            ground_speed: parse_field(fields, 6, "ground_speed")?,
+            ground_course: parse_field(fields, 7, "ground_course")?,
+            fix_mode: parse_field::<u8>(fields, 8, "fix_mode")?
+                .map_or(FixStatus::Unknown, FixStatus::from),
             // Reserved1
-            hdop: fields[10].parse().ok(),
-            pdop: fields[11].parse().ok(),
-            vdop: fields[12].parse().ok(),
+            hdop: parse_field(fields, 10, "hdop")?,
+            pdop: parse_field(fields, 11, "pdop")?,
+            vdop: parse_field(fields, 12, "vdop")?,
             // Reserved2
-            gps_in_view: fields[14].parse().ok(),
-            gnss_used: fields[15].parse().ok(),
-            glonass_in_view: fields[16].parse().ok(),
+            gps_in_view: parse_field(fields, 14, "gps_in_view")?,
+            gnss_used: parse_field(fields, 15, "gnss_used")?,
+            glonass_in_view: parse_field(fields, 16, "glonass_in_view")?,
+            satellites: Vec::new(),
         })
     }
 }
-impl std::fmt::Display for PositionReport {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        fn convert_opt<T: std::fmt::Display>(opt: Option<&T>) -> String {
-            match opt {
-                Some(value) => value.to_string(),
-                None => "None".to_string(),
+impl core::str::FromStr for PositionReport {
+    type Err = GnssParseError;
+
+    /// Parse a report from a `+CGNSINF` response line, with or without its prefix.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let data = s.strip_prefix("+CGNSINF:").unwrap_or(s);
+        let fields: Vec<&str> = data.split(',').collect();
+        Self::from_fields(&fields)
+    }
+}
+impl TryFrom<Vec<&str>> for PositionReport {
+    type Error = GnssParseError;
+
+    fn try_from(fields: Vec<&str>) -> Result<Self, Self::Error> {
+        Self::from_fields(&fields)
+    }
+}
+
+/// An error parsing a GNSS position report.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum GnssParseError {
+    /// There were not enough fields to parse a report.
+    InsufficientFields {
+        /// The minimum amount of fields required.
+        expected: usize,
+
+        /// The amount of fields received.
+        got: usize,
+    },
+
+    /// A present field could not be parsed.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+
+        /// The index of the field.
+        index: usize,
+
+        /// The invalid field value.
+        value: String,
+    },
+}
+impl core::fmt::Display for GnssParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GnssParseError::InsufficientFields { expected, got } => {
+                write!(
+                    f,
+                    "Insufficient GNSS data fields, expected {expected} got {got}"
+                )
             }
+            GnssParseError::InvalidField {
+                field,
+                index,
+                value,
+            } => write!(f, "Invalid GNSS {field} field at index {index}: '{value}'"),
         }
+    }
+}
+impl core::error::Error for GnssParseError {}
+
+/// Parse an optional field, returning None if it's missing or empty.
+fn parse_field<T: core::str::FromStr>(
+    fields: &[&str],
+    index: usize,
+    field: &'static str,
+) -> Result<Option<T>, GnssParseError> {
+    match fields.get(index).map(|v| v.trim()) {
+        None | Some("") => Ok(None),
+        Some(value) => value
+            .parse()
+            .map(Some)
+            .map_err(|_| GnssParseError::InvalidField {
+                field,
+                index,
+                value: value.to_string(),
+            }),
+    }
+}
 
+/// Parse a 0/1 flag field, returning false if it's missing or empty.
+fn parse_flag(fields: &[&str], index: usize, field: &'static str) -> Result<bool, GnssParseError> {
+    match parse_field::<u8>(fields, index, field)? {
+        None | Some(0) => Ok(false),
+        Some(1) => Ok(true),
+        Some(_) => Err(GnssParseError::InvalidField {
+            field,
+            index,
+            value: fields[index].trim().to_string(),
+        }),
+    }
+}
+
+/// A position report with the time it was received from the modem.
+/// The modem repeats its last fix after losing satellites, so the receive
+/// time should be used to check if a position is still current.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct TimedPosition {
+    /// The position report.
+    #[serde(flatten)]
+    pub report: PositionReport,
+
+    /// Unix timestamp in milliseconds when the report was received.
+    pub received_at: u64,
+
+    /// A cell based location, included when there is no satellite fix.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coarse: Option<CoarseLocation>,
+}
+impl TimedPosition {
+    /// Create a timed position received at a Unix timestamp in milliseconds.
+    #[must_use]
+    pub fn new(report: PositionReport, received_at: u64) -> Self {
+        Self {
+            report,
+            received_at,
+            coarse: None,
+        }
+    }
+
+    /// Attach a cell based location, for when there is no satellite fix.
+    #[must_use]
+    pub fn with_coarse_location(mut self, coarse: CoarseLocation) -> Self {
+        self.coarse = Some(coarse);
+        self
+    }
+
+    /// Get the best available point, the satellite fix if there is one or
+    /// otherwise the resolved coarse location.
+    #[must_use]
+    pub fn best_point(&self) -> Option<GeoPoint> {
+        self.report
+            .point()
+            .filter(|_| self.report.fix_status)
+            .or_else(|| self.coarse.as_ref()?.location)
+    }
+
+    /// Create a timed position received now.
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    #[must_use]
+    pub fn received_now(report: PositionReport) -> Self {
+        Self::new(report, Timestamp::now().as_unix_millis())
+    }
+
+    /// Get when the report was received.
+    #[must_use]
+    pub const fn received_timestamp(&self) -> Timestamp {
+        Timestamp::from_unix_millis(self.received_at)
+    }
+
+    /// Get the age of the position at a Unix timestamp in milliseconds.
+    #[must_use]
+    pub fn age_at(&self, now: u64) -> core::time::Duration {
+        core::time::Duration::from_millis(now.saturating_sub(self.received_at))
+    }
+
+    /// Get the current age of the position.
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    #[must_use]
+    pub fn age(&self) -> core::time::Duration {
+        self.age_at(Timestamp::now().as_unix_millis())
+    }
+
+    /// Check if the position is older than a threshold.
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    #[must_use]
+    pub fn is_stale(&self, threshold: core::time::Duration) -> bool {
+        self.age() > threshold
+    }
+}
+
+impl core::fmt::Display for PositionReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "Lat: {}, Lon: {}, Alt: {}, Speed: {}, Course: {}",
@@ -142,3 +1126,47 @@ impl std::fmt::Display for PositionReport {
         )
     }
 }
+
+/// Display a position report with speed and altitude in chosen units,
+/// created by `PositionReport::display_with`.
+#[derive(Debug, Clone, Copy)]
+pub struct PositionReportDisplay<'a> {
+    report: &'a PositionReport,
+    speed_unit: SpeedUnit,
+    distance_unit: DistanceUnit,
+}
+impl core::fmt::Display for PositionReportDisplay<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let altitude = self.report.msl_altitude.map(|meters| {
+            format!(
+                "{:.1} {}",
+                self.distance_unit.convert(meters),
+                self.distance_unit
+            )
+        });
+        let speed = self.report.ground_speed.map(|mps| {
+            format!(
+                "{:.1} {}",
+                self.speed_unit.convert(f64::from(mps)),
+                self.speed_unit
+            )
+        });
+
+        write!(
+            f,
+            "Lat: {}, Lon: {}, Alt: {}, Speed: {}, Course: {}",
+            convert_opt(self.report.latitude.as_ref()),
+            convert_opt(self.report.longitude.as_ref()),
+            convert_opt(altitude.as_ref()),
+            convert_opt(speed.as_ref()),
+            convert_opt(self.report.ground_course.as_ref())
+        )
+    }
+}
+
+fn convert_opt<T: core::fmt::Display>(opt: Option<&T>) -> String {
+    match opt {
+        Some(value) => value.to_string(),
+        None => "None".to_string(),
+    }
+}
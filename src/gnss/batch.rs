@@ -0,0 +1,196 @@
+use super::{GeoPoint, TimedPosition};
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use serde::{Deserialize, Serialize};
+
+/// Scale applied to latitude and longitude degrees (~0.11m resolution).
+pub const COORDINATE_SCALE: f64 = 1e6;
+
+/// Scale applied to altitude meters (decimeter resolution).
+pub const ALTITUDE_SCALE: f64 = 10.0;
+
+/// A position sample decoded from a `PositionBatch`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct PositionSample {
+    /// The position.
+    pub point: GeoPoint,
+
+    /// MSL altitude in meters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub altitude: Option<f64>,
+
+    /// Unix timestamp in milliseconds when the position was received.
+    pub received_at: u64,
+}
+
+/// A scaled integer position, either absolute (the batch base) or relative to the previous point.
+/// Serialized as a compact `[time, latitude, longitude, altitude]` array.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "ts-rs",
+    derive(ts_rs::TS),
+    ts(as = "(i64, i32, i32, Option<i32>)")
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[serde(
+    from = "(i64, i32, i32, Option<i32>)",
+    into = "(i64, i32, i32, Option<i32>)"
+)]
+pub struct PositionDelta {
+    /// Time in milliseconds.
+    pub time: i64,
+
+    /// Latitude scaled by `COORDINATE_SCALE`.
+    pub latitude: i32,
+
+    /// Longitude scaled by `COORDINATE_SCALE`.
+    pub longitude: i32,
+
+    /// Altitude scaled by `ALTITUDE_SCALE`, relative to the last point with an altitude.
+    pub altitude: Option<i32>,
+}
+impl From<(i64, i32, i32, Option<i32>)> for PositionDelta {
+    fn from(value: (i64, i32, i32, Option<i32>)) -> Self {
+        Self {
+            time: value.0,
+            latitude: value.1,
+            longitude: value.2,
+            altitude: value.3,
+        }
+    }
+}
+impl From<PositionDelta> for (i64, i32, i32, Option<i32>) {
+    fn from(value: PositionDelta) -> Self {
+        (value.time, value.latitude, value.longitude, value.altitude)
+    }
+}
+
+/// A sequence of positions encoded as a base fix plus per-point deltas,
+/// to make store-and-forward of long tracks much smaller.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct PositionBatch {
+    /// The first position, as absolute scaled values.
+    pub base: PositionDelta,
+
+    /// The following positions, each relative to the one before it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deltas: Vec<PositionDelta>,
+}
+impl PositionBatch {
+    /// Encode a sequence of positions, skipping those without a location.
+    /// Returns None if there are no positions with a location, or if a delta overflows.
+    #[must_use]
+    pub fn encode(positions: &[TimedPosition]) -> Option<Self> {
+        let mut scaled = positions.iter().filter_map(|position| {
+            let point = position.report.point()?;
+            Some(PositionDelta {
+                time: i64::try_from(position.received_at).unwrap_or(i64::MAX),
+                latitude: scale(point.latitude, COORDINATE_SCALE),
+                longitude: scale(point.longitude, COORDINATE_SCALE),
+                altitude: position
+                    .report
+                    .msl_altitude
+                    .map(|altitude| scale(altitude, ALTITUDE_SCALE)),
+            })
+        });
+
+        let base = scaled.next()?;
+        let mut previous = base;
+        let mut last_altitude = base.altitude;
+        let mut deltas = Vec::new();
+        for current in scaled {
+            deltas.push(PositionDelta {
+                time: current.time.checked_sub(previous.time)?,
+                latitude: current.latitude.checked_sub(previous.latitude)?,
+                longitude: current.longitude.checked_sub(previous.longitude)?,
+                altitude: match current.altitude {
+                    Some(altitude) => Some(altitude.checked_sub(last_altitude.unwrap_or(0))?),
+                    None => None,
+                },
+            });
+            previous = current;
+            last_altitude = current.altitude.or(last_altitude);
+        }
+
+        Some(Self { base, deltas })
+    }
+
+    /// Decode the batch back into position samples.
+    /// Returns None if applying a delta overflows, which only happens for a malformed batch.
+    #[must_use]
+    pub fn decode(&self) -> Option<Vec<PositionSample>> {
+        let mut current = self.base;
+        let mut last_altitude = self.base.altitude;
+        let mut samples = Vec::with_capacity(self.len());
+        samples.push(sample(&current));
+
+        for delta in &self.deltas {
+            current.time = current.time.checked_add(delta.time)?;
+            current.latitude = current.latitude.checked_add(delta.latitude)?;
+            current.longitude = current.longitude.checked_add(delta.longitude)?;
+            current.altitude = match delta.altitude {
+                Some(altitude) => Some(last_altitude.unwrap_or(0).checked_add(altitude)?),
+                None => None,
+            };
+            last_altitude = current.altitude.or(last_altitude);
+            samples.push(sample(&current));
+        }
+        Some(samples)
+    }
+
+    /// Get the amount of positions in the batch.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.deltas.len() + 1
+    }
+
+    /// A batch always contains at least the base position.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+/// Scale and round a value to an integer, saturating at the i32 range.
+#[allow(clippy::cast_possible_truncation)]
+fn scale(value: f64, scale: f64) -> i32 {
+    (value * scale).round() as i32
+}
+
+fn sample(scaled: &PositionDelta) -> PositionSample {
+    PositionSample {
+        point: GeoPoint::new(
+            f64::from(scaled.latitude) / COORDINATE_SCALE,
+            f64::from(scaled.longitude) / COORDINATE_SCALE,
+        ),
+        altitude: scaled
+            .altitude
+            .map(|altitude| f64::from(altitude) / ALTITUDE_SCALE),
+        received_at: u64::try_from(scaled.time).unwrap_or(0),
+    }
+}
@@ -0,0 +1,64 @@
+use super::GeoPoint;
+use serde::{Deserialize, Serialize};
+
+/// A coarse location derived from the serving cell, used as a fallback when
+/// there is no satellite fix, eg: indoors.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct CoarseLocation {
+    /// Mobile Country Code.
+    pub mcc: u16,
+
+    /// Mobile Network Code.
+    pub mnc: u16,
+
+    /// Location Area Code (or Tracking Area Code on LTE).
+    pub lac: u32,
+
+    /// Serving cell identifier.
+    pub cell_id: u32,
+
+    /// The cell location, if it has been resolved from a cell database.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<GeoPoint>,
+
+    /// The accuracy radius of the resolved location in meters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accuracy: Option<f64>,
+}
+impl CoarseLocation {
+    /// Create an unresolved coarse location from serving cell info.
+    #[must_use]
+    pub const fn new(mcc: u16, mnc: u16, lac: u32, cell_id: u32) -> Self {
+        Self {
+            mcc,
+            mnc,
+            lac,
+            cell_id,
+            location: None,
+            accuracy: None,
+        }
+    }
+
+    /// Set the resolved cell location and its accuracy radius in meters.
+    #[must_use]
+    pub fn with_location(mut self, location: GeoPoint, accuracy: f64) -> Self {
+        self.location = Some(location);
+        self.accuracy = Some(accuracy);
+        self
+    }
+
+    /// Check if the cell location has been resolved.
+    #[must_use]
+    pub fn is_resolved(&self) -> bool {
+        self.location.is_some()
+    }
+}
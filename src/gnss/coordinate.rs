@@ -0,0 +1,288 @@
+use alloc::{format, string::String};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use serde::{Deserialize, Serialize};
+
+/// Whether a coordinate is a latitude or a longitude.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub enum CoordinateAxis {
+    /// North-south position, in the range -90 to 90.
+    Latitude,
+
+    /// East-west position, in the range -180 to 180.
+    Longitude,
+}
+impl core::fmt::Display for CoordinateAxis {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            CoordinateAxis::Latitude => "Latitude",
+            CoordinateAxis::Longitude => "Longitude",
+        })
+    }
+}
+
+/// The hemisphere of a coordinate, used by DMS and NMEA formats instead of a sign.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub enum Hemisphere {
+    /// North of the equator.
+    North,
+
+    /// South of the equator.
+    South,
+
+    /// East of the prime meridian.
+    East,
+
+    /// West of the prime meridian.
+    West,
+}
+impl Hemisphere {
+    /// Get the hemisphere from its letter (N, S, E or W).
+    #[must_use]
+    pub fn from_letter(letter: char) -> Option<Self> {
+        match letter.to_ascii_uppercase() {
+            'N' => Some(Hemisphere::North),
+            'S' => Some(Hemisphere::South),
+            'E' => Some(Hemisphere::East),
+            'W' => Some(Hemisphere::West),
+            _ => None,
+        }
+    }
+
+    /// Get the hemisphere letter.
+    #[must_use]
+    pub const fn letter(self) -> char {
+        match self {
+            Hemisphere::North => 'N',
+            Hemisphere::South => 'S',
+            Hemisphere::East => 'E',
+            Hemisphere::West => 'W',
+        }
+    }
+
+    /// Get the axis this hemisphere applies to.
+    #[must_use]
+    pub const fn axis(self) -> CoordinateAxis {
+        match self {
+            Hemisphere::North | Hemisphere::South => CoordinateAxis::Latitude,
+            Hemisphere::East | Hemisphere::West => CoordinateAxis::Longitude,
+        }
+    }
+
+    /// Check if coordinates in this hemisphere are negative in decimal degrees.
+    #[must_use]
+    pub const fn is_negative(self) -> bool {
+        matches!(self, Hemisphere::South | Hemisphere::West)
+    }
+}
+impl core::fmt::Display for Hemisphere {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.letter())
+    }
+}
+
+/// A coordinate in degrees, minutes and seconds.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct Dms {
+    /// Whole degrees.
+    pub degrees: u16,
+
+    /// Whole minutes (0 - 59).
+    pub minutes: u8,
+
+    /// Seconds (0 - 60).
+    pub seconds: f64,
+
+    /// The hemisphere of the coordinate.
+    pub hemisphere: Hemisphere,
+}
+impl core::fmt::Display for Dms {
+    /// Format as `51°30'26.46"N`, with seconds rounded to two decimal places.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Round in hundredths of a second so 59.999 carries into the minutes.
+        let total = u64::from(self.degrees) * 360_000
+            + u64::from(self.minutes) * 6000
+            + (self.seconds * 100.0).round() as u64;
+        write!(
+            f,
+            "{}°{:02}'{:02}.{:02}\"{}",
+            total / 360_000,
+            total / 6000 % 60,
+            total / 100 % 60,
+            total % 100,
+            self.hemisphere
+        )
+    }
+}
+
+/// A latitude or longitude, stored in decimal degrees.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct Coordinate {
+    /// The value in decimal degrees, negative for south or west.
+    pub degrees: f64,
+
+    /// Whether this is a latitude or longitude.
+    pub axis: CoordinateAxis,
+}
+impl Coordinate {
+    /// Create a latitude from decimal degrees.
+    #[must_use]
+    pub const fn latitude(degrees: f64) -> Self {
+        Self {
+            degrees,
+            axis: CoordinateAxis::Latitude,
+        }
+    }
+
+    /// Create a longitude from decimal degrees.
+    #[must_use]
+    pub const fn longitude(degrees: f64) -> Self {
+        Self {
+            degrees,
+            axis: CoordinateAxis::Longitude,
+        }
+    }
+
+    /// Check if the value is within the valid range for its axis.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        let limit = match self.axis {
+            CoordinateAxis::Latitude => 90.0,
+            CoordinateAxis::Longitude => 180.0,
+        };
+        self.degrees.is_finite() && self.degrees.abs() <= limit
+    }
+
+    /// Get the hemisphere of the coordinate.
+    #[must_use]
+    pub fn hemisphere(&self) -> Hemisphere {
+        match (self.axis, self.degrees.is_sign_negative()) {
+            (CoordinateAxis::Latitude, false) => Hemisphere::North,
+            (CoordinateAxis::Latitude, true) => Hemisphere::South,
+            (CoordinateAxis::Longitude, false) => Hemisphere::East,
+            (CoordinateAxis::Longitude, true) => Hemisphere::West,
+        }
+    }
+
+    /// Convert into degrees, minutes and seconds.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn to_dms(&self) -> Dms {
+        let absolute = self.degrees.abs();
+        let minutes = absolute.fract() * 60.0;
+        Dms {
+            degrees: absolute.trunc() as u16,
+            minutes: minutes.trunc() as u8,
+            seconds: minutes.fract() * 60.0,
+            hemisphere: self.hemisphere(),
+        }
+    }
+
+    /// Create from degrees, minutes and seconds.
+    #[must_use]
+    pub fn from_dms(dms: &Dms) -> Self {
+        let absolute =
+            f64::from(dms.degrees) + f64::from(dms.minutes) / 60.0 + dms.seconds / 3600.0;
+        Self {
+            degrees: if dms.hemisphere.is_negative() {
+                -absolute
+            } else {
+                absolute
+            },
+            axis: dms.hemisphere.axis(),
+        }
+    }
+
+    /// Convert into an NMEA `ddmm.mmmm` (latitude) or `dddmm.mmmm` (longitude)
+    /// value, along with its hemisphere.
+    #[must_use]
+    pub fn to_nmea(&self) -> (String, Hemisphere) {
+        let absolute = self.degrees.abs();
+        let width = match self.axis {
+            CoordinateAxis::Latitude => 2,
+            CoordinateAxis::Longitude => 3,
+        };
+
+        // Round the minutes first so 59.99999 carries into the degrees.
+        let mut degrees = absolute.trunc();
+        let mut minutes = (absolute.fract() * 60.0 * 10_000.0).round() / 10_000.0;
+        if minutes >= 60.0 {
+            degrees += 1.0;
+            minutes -= 60.0;
+        }
+        (
+            format!("{degrees:0width$}{minutes:07.4}"),
+            self.hemisphere(),
+        )
+    }
+
+    /// Parse an NMEA `ddmm.mmmm` or `dddmm.mmmm` value with its hemisphere letter.
+    /// Returns None if the value is empty or invalid.
+    #[must_use]
+    pub fn from_nmea(value: &str, hemisphere: char) -> Option<Self> {
+        let hemisphere = Hemisphere::from_letter(hemisphere)?;
+        let value = value.trim();
+        let split = value.find('.').unwrap_or(value.len()).checked_sub(2)?;
+        if !value.is_ascii() {
+            return None;
+        }
+
+        let degrees: f64 = value[..split].parse().ok()?;
+        let minutes: f64 = value[split..].parse().ok()?;
+        if minutes >= 60.0 {
+            return None;
+        }
+
+        let absolute = degrees + minutes / 60.0;
+        let coordinate = Self {
+            degrees: if hemisphere.is_negative() {
+                -absolute
+            } else {
+                absolute
+            },
+            axis: hemisphere.axis(),
+        };
+        coordinate.is_valid().then_some(coordinate)
+    }
+}
+impl core::fmt::Display for Coordinate {
+    /// Format in decimal degrees, with 6 decimal places (about 0.1m) by default.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:.*}", f.precision().unwrap_or(6), self.degrees)
+    }
+}
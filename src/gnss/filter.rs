@@ -0,0 +1,69 @@
+use super::PositionReport;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// Smooths successive position reports with a moving average over latitude,
+/// longitude and ground speed, so live tracks don't show GPS jitter.
+#[derive(Debug, Clone)]
+pub struct PositionFilter {
+    window: usize,
+    samples: VecDeque<(f64, f64, Option<f32>)>,
+}
+impl PositionFilter {
+    /// Create a filter averaging over the last `window` reports with a position.
+    /// A window of 1 (or 0) passes reports through unchanged.
+    #[must_use]
+    pub fn new(window: usize) -> Self {
+        let window = window.max(1);
+        Self {
+            window,
+            samples: VecDeque::with_capacity(window),
+        }
+    }
+
+    /// Clear all previous samples, eg: after the fix was lost for a long time.
+    pub fn reset(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Add a report to the filter and return its smoothed copy.
+    /// Reports without a position are returned unchanged and are not sampled.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    pub fn push(&mut self, report: &PositionReport) -> PositionReport {
+        let Some(point) = report.point() else {
+            return report.clone();
+        };
+        if self.samples.len() == self.window {
+            self.samples.pop_front();
+        }
+        self.samples
+            .push_back((point.latitude, point.longitude, report.ground_speed));
+
+        // Unwrap longitudes relative to the newest sample, so averaging
+        // across the antimeridian doesn't produce a point on the other side of the world.
+        let count = self.samples.len() as f64;
+        let (latitude, longitude) = self.samples.iter().fold((0.0, 0.0), |acc, sample| {
+            let mut longitude = sample.1;
+            if longitude - point.longitude > 180.0 {
+                longitude -= 360.0;
+            } else if longitude - point.longitude < -180.0 {
+                longitude += 360.0;
+            }
+            (acc.0 + sample.0 / count, acc.1 + longitude / count)
+        });
+
+        // Only smooth the speed if this report has one.
+        let speeds: Vec<f32> = self.samples.iter().filter_map(|s| s.2).collect();
+        let ground_speed = report
+            .ground_speed
+            .map(|_| speeds.iter().sum::<f32>() / speeds.len() as f32);
+
+        PositionReport {
+            latitude: Some(latitude),
+            longitude: Some(crate::gnss::rem_euclid(longitude + 540.0, 360.0) - 180.0),
+            ground_speed,
+            ..report.clone()
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Geofence areas with containment checks, and their transition event payloads.
+
+use super::{GeoPoint, PositionReport, segment_distance};
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+/// A circular area around a center point.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct Circle {
+    /// The center of the circle.
+    pub center: GeoPoint,
+
+    /// The radius of the circle in meters.
+    pub radius: f64,
+}
+impl Circle {
+    /// Get the distance in meters from the circle boundary, negative when inside.
+    #[must_use]
+    pub fn boundary_distance(&self, point: &GeoPoint) -> f64 {
+        self.center.distance_to(point) - self.radius
+    }
+}
+
+/// A polygon area, with vertices in order. The polygon is implicitly closed.
+/// This is intended for areas up to a few tens of kilometers across.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct Polygon {
+    /// The polygon vertices.
+    pub vertices: Vec<GeoPoint>,
+}
+impl Polygon {
+    /// Check if a point lies within the polygon, using ray casting.
+    #[must_use]
+    pub fn contains_point(&self, point: &GeoPoint) -> bool {
+        let mut inside = false;
+        let Some(mut previous) = self.vertices.last() else {
+            return false;
+        };
+        for vertex in &self.vertices {
+            if (vertex.latitude > point.latitude) != (previous.latitude > point.latitude) {
+                let crossing = (previous.longitude - vertex.longitude)
+                    * (point.latitude - vertex.latitude)
+                    / (previous.latitude - vertex.latitude)
+                    + vertex.longitude;
+                if point.longitude < crossing {
+                    inside = !inside;
+                }
+            }
+            previous = vertex;
+        }
+        inside
+    }
+
+    /// Get the distance in meters from the polygon boundary, negative when inside.
+    #[must_use]
+    pub fn boundary_distance(&self, point: &GeoPoint) -> f64 {
+        let Some(mut previous) = self.vertices.last() else {
+            return f64::INFINITY;
+        };
+        let mut nearest = f64::INFINITY;
+        for vertex in &self.vertices {
+            nearest = nearest.min(segment_distance(previous, vertex, point));
+            previous = vertex;
+        }
+
+        if self.contains_point(point) {
+            -nearest
+        } else {
+            nearest
+        }
+    }
+}
+
+/// The area covered by a geofence.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[serde(tag = "shape")]
+#[non_exhaustive]
+pub enum GeofenceArea {
+    /// A circular area.
+    Circle(Circle),
+
+    /// A polygon area.
+    Polygon(Polygon),
+}
+impl GeofenceArea {
+    /// Get the distance in meters from the area boundary, negative when inside.
+    #[must_use]
+    pub fn boundary_distance(&self, point: &GeoPoint) -> f64 {
+        match self {
+            GeofenceArea::Circle(circle) => circle.boundary_distance(point),
+            GeofenceArea::Polygon(polygon) => polygon.boundary_distance(point),
+        }
+    }
+}
+
+/// A named area that produces transitions as positions enter and exit it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct Geofence {
+    /// Unique identifier for the geofence.
+    pub id: String,
+
+    /// The area covered by the geofence.
+    pub area: GeofenceArea,
+
+    /// Distance in meters a position must move past the boundary before a
+    /// transition is reported, preventing GPS jitter near the boundary from
+    /// producing repeated enter and exit transitions.
+    #[serde(default)]
+    pub hysteresis: f64,
+}
+impl Geofence {
+    /// Create a new geofence without hysteresis.
+    pub fn new(id: impl Into<String>, area: GeofenceArea) -> Self {
+        Self {
+            id: id.into(),
+            area,
+            hysteresis: 0.0,
+        }
+    }
+
+    /// Set the hysteresis distance in meters.
+    #[must_use]
+    pub fn with_hysteresis(mut self, hysteresis: f64) -> Self {
+        self.hysteresis = hysteresis;
+        self
+    }
+
+    /// Check if a position report is within the geofence, ignoring hysteresis.
+    /// Returns false if the report has no position.
+    #[must_use]
+    pub fn contains(&self, report: &PositionReport) -> bool {
+        report
+            .point()
+            .is_some_and(|point| self.area.boundary_distance(&point) <= 0.0)
+    }
+
+    /// Get the transition caused by a position report, if any, given whether the
+    /// position was previously inside. Hysteresis is applied in both directions.
+    #[must_use]
+    pub fn transition(
+        &self,
+        was_inside: bool,
+        report: &PositionReport,
+    ) -> Option<GeofenceTransition> {
+        let distance = self.area.boundary_distance(&report.point()?);
+        match was_inside {
+            true if distance > self.hysteresis => Some(GeofenceTransition::Exit),
+            false if distance < -self.hysteresis => Some(GeofenceTransition::Enter),
+            _ => None,
+        }
+    }
+
+    /// Get the event payload for a transition caused by a position report, if any.
+    #[must_use]
+    pub fn event(&self, was_inside: bool, report: &PositionReport) -> Option<GeofenceEvent> {
+        self.transition(was_inside, report)
+            .map(|transition| GeofenceEvent {
+                geofence_id: self.id.clone(),
+                transition,
+                report: report.clone(),
+            })
+    }
+}
+
+/// The direction of a geofence transition.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
+pub enum GeofenceTransition {
+    /// The position entered the geofence.
+    Enter,
+
+    /// The position exited the geofence.
+    Exit,
+}
+impl core::fmt::Display for GeofenceTransition {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            GeofenceTransition::Enter => "Enter",
+            GeofenceTransition::Exit => "Exit",
+        })
+    }
+}
+
+/// A geofence transition, with the position report that triggered it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct GeofenceEvent {
+    /// The id of the geofence that was entered or exited.
+    pub geofence_id: String,
+
+    /// The direction of the transition.
+    pub transition: GeofenceTransition,
+
+    /// The position report that triggered the transition.
+    pub report: PositionReport,
+}
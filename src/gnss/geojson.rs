@@ -0,0 +1,65 @@
+use super::{PositionReport, Track};
+use ::geojson::{Feature, Geometry, JsonObject, JsonValue};
+
+/// Get the `GeoJSON` position (longitude, latitude, optional altitude) of a report.
+fn position(report: &PositionReport) -> Option<Vec<f64>> {
+    let point = report.point()?;
+    let mut position = vec![point.longitude, point.latitude];
+    position.extend(report.msl_altitude);
+    Some(position)
+}
+
+/// Convert a position report into a `GeoJSON` `Point` feature, with all other
+/// report values as properties. The geometry is null if there is no position.
+impl From<&PositionReport> for Feature {
+    fn from(report: &PositionReport) -> Self {
+        let mut properties = match serde_json::to_value(report) {
+            Ok(JsonValue::Object(properties)) => properties,
+            _ => JsonObject::new(),
+        };
+        properties.remove("latitude");
+        properties.remove("longitude");
+
+        Feature {
+            bbox: None,
+            geometry: position(report).map(Geometry::new_point),
+            id: None,
+            properties: Some(properties),
+            foreign_members: None,
+        }
+    }
+}
+
+/// Convert a track into a `GeoJSON` `LineString` geometry, skipping reports without a position.
+impl From<&Track> for Geometry {
+    fn from(track: &Track) -> Self {
+        Geometry::new_line_string(track.reports.iter().filter_map(position))
+    }
+}
+
+/// Convert a track into a `GeoJSON` `LineString` feature, with the total distance
+/// in meters and duration in seconds as properties.
+impl From<&Track> for Feature {
+    fn from(track: &Track) -> Self {
+        let mut properties = JsonObject::new();
+        properties.insert("distance".to_string(), track.total_distance().into());
+        if let Some(duration) = track.duration() {
+            properties.insert("duration".to_string(), duration.as_secs_f64().into());
+        }
+
+        Feature {
+            bbox: track.bounding_box().map(|bounds| {
+                vec![
+                    bounds.min.longitude,
+                    bounds.min.latitude,
+                    bounds.max.longitude,
+                    bounds.max.latitude,
+                ]
+            }),
+            geometry: Some(Geometry::from(track)),
+            id: None,
+            properties: Some(properties),
+            foreign_members: None,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use super::{FixStatus, PositionReport, TimedPosition, Track};
+use alloc::string::String;
+use core::fmt::Write;
+
+impl Track {
+    /// Serialize the track as a GPX 1.1 document with a single track segment.
+    /// Reports without a position are skipped.
+    #[must_use]
+    pub fn to_gpx(&self) -> String {
+        write_gpx(
+            self.reports
+                .iter()
+                .map(|report| (report, report.utc_time_millis())),
+        )
+    }
+}
+
+/// Serialize a sequence of timed positions as a GPX 1.1 document with a single track segment.
+/// The reported UTC time is used where available, falling back to when it was received.
+/// Positions without a fix location are skipped.
+#[must_use]
+pub fn positions_to_gpx(positions: &[TimedPosition]) -> String {
+    write_gpx(positions.iter().map(|position| {
+        let time = position
+            .report
+            .utc_time_millis()
+            .unwrap_or(position.received_at);
+        (&position.report, Some(time))
+    }))
+}
+
+fn write_gpx<'a>(points: impl Iterator<Item = (&'a PositionReport, Option<u64>)>) -> String {
+    let mut gpx = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        "\n",
+        r#"<gpx version="1.1" creator="sms-types" xmlns="http://www.topografix.com/GPX/1/1">"#,
+        "\n<trk><trkseg>\n"
+    ));
+
+    // Writing into a String cannot fail.
+    for (report, time) in points {
+        let Some(point) = report.point() else {
+            continue;
+        };
+        let _ = write!(
+            gpx,
+            r#"<trkpt lat="{}" lon="{}">"#,
+            point.latitude, point.longitude
+        );
+        if let Some(altitude) = report.msl_altitude {
+            let _ = write!(gpx, "<ele>{altitude}</ele>");
+        }
+        if let Some(time) = time {
+            let _ = write!(
+                gpx,
+                "<time>{}</time>",
+                crate::timestamp::Timestamp::from_unix_millis(time)
+            );
+        }
+        match report.fix_mode {
+            FixStatus::NotFix => gpx.push_str("<fix>none</fix>"),
+            FixStatus::Fix2D => gpx.push_str("<fix>2d</fix>"),
+            FixStatus::Fix3D => gpx.push_str("<fix>3d</fix>"),
+            FixStatus::Unknown => {}
+        }
+        if let Some(used) = report.gnss_used {
+            let _ = write!(gpx, "<sat>{used}</sat>");
+        }
+        for (name, dop) in [
+            ("hdop", report.hdop),
+            ("vdop", report.vdop),
+            ("pdop", report.pdop),
+        ] {
+            if let Some(dop) = dop {
+                let _ = write!(gpx, "<{name}>{dop}</{name}>");
+            }
+        }
+        gpx.push_str("</trkpt>\n");
+    }
+
+    gpx.push_str("</trkseg></trk>\n</gpx>\n");
+    gpx
+}
@@ -0,0 +1,74 @@
+use super::{GeoPoint, TimedPosition};
+use serde::{Deserialize, Serialize};
+
+/// The last valid fix, used to answer "where was it last seen" once the
+/// live fix has been lost. Reports without a fix never replace it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct LastKnownPosition {
+    /// The last position that had a valid fix.
+    pub position: TimedPosition,
+}
+impl LastKnownPosition {
+    /// Create from a timed position, returning None if it has no valid fix.
+    #[must_use]
+    pub fn from_fix(position: TimedPosition) -> Option<Self> {
+        Self::is_valid_fix(&position).then_some(Self { position })
+    }
+
+    /// Replace the last known position if the new position has a valid fix
+    /// and is not older. Returns true if the position was updated.
+    pub fn update(&mut self, position: &TimedPosition) -> bool {
+        if !Self::is_valid_fix(position) || position.received_at < self.position.received_at {
+            return false;
+        }
+        self.position = position.clone();
+        true
+    }
+
+    /// Get the last known point.
+    #[must_use]
+    pub fn point(&self) -> Option<GeoPoint> {
+        self.position.report.point()
+    }
+
+    /// Get the time elapsed since the fix at a Unix timestamp in milliseconds.
+    #[must_use]
+    pub fn elapsed_at(&self, now: u64) -> core::time::Duration {
+        self.position.age_at(now)
+    }
+
+    /// Get the time elapsed since the fix.
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    #[must_use]
+    pub fn elapsed(&self) -> core::time::Duration {
+        self.position.age()
+    }
+
+    /// Estimate the current point at a Unix timestamp in milliseconds by dead reckoning,
+    /// assuming the last ground speed and course were maintained.
+    /// Returns None if the last fix has no position, speed or course.
+    #[must_use]
+    pub fn extrapolate_at(&self, now: u64) -> Option<GeoPoint> {
+        let report = &self.position.report;
+        let speed = f64::from(report.ground_speed?);
+        let course = f64::from(report.ground_course?);
+        let distance = speed * self.elapsed_at(now).as_secs_f64();
+        Some(self.point()?.destination(course, distance))
+    }
+
+    fn is_valid_fix(position: &TimedPosition) -> bool {
+        position.report.fix_status && position.report.point().is_some()
+    }
+}
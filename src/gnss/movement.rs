@@ -0,0 +1,132 @@
+use super::{GeoPoint, PositionReport};
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+/// A movement condition that raises an alert when exceeded, eg: for theft detection.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all_fields = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[serde(tag = "type")]
+#[non_exhaustive]
+pub enum MovementThreshold {
+    /// Ground speed over a limit in meters per second.
+    Speed {
+        /// The speed limit in meters per second.
+        limit: f64,
+    },
+
+    /// Moved further than a distance in meters from an anchor point.
+    Distance {
+        /// The point the distance is measured from, eg: where a vehicle was parked.
+        anchor: GeoPoint,
+
+        /// The maximum distance from the anchor in meters.
+        radius: f64,
+    },
+}
+impl MovementThreshold {
+    /// Get the measured speed or distance if the report exceeds the threshold.
+    /// Returns None if it isn't exceeded or the report is missing the value.
+    #[must_use]
+    pub fn exceeded_by(&self, report: &PositionReport) -> Option<f64> {
+        let (value, limit) = match self {
+            MovementThreshold::Speed { limit } => (f64::from(report.ground_speed?), *limit),
+            MovementThreshold::Distance { anchor, radius } => {
+                (anchor.distance_to(&report.point()?), *radius)
+            }
+        };
+        (value > limit).then_some(value)
+    }
+}
+
+/// A named movement alert configuration.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct MovementAlertConfig {
+    /// A unique identifier for the alert.
+    pub id: String,
+
+    /// The condition that raises the alert.
+    pub threshold: MovementThreshold,
+
+    /// Only raise the alert for reports with a valid fix.
+    #[serde(default = "default_require_fix")]
+    pub require_fix: bool,
+}
+impl MovementAlertConfig {
+    /// Create a new alert configuration that requires a valid fix.
+    pub fn new(id: impl Into<String>, threshold: MovementThreshold) -> Self {
+        Self {
+            id: id.into(),
+            threshold,
+            require_fix: true,
+        }
+    }
+
+    /// Set whether reports without a valid fix can raise the alert.
+    #[must_use]
+    pub fn with_require_fix(mut self, require_fix: bool) -> Self {
+        self.require_fix = require_fix;
+        self
+    }
+
+    /// Get the alert payload for a position report, if it exceeds the threshold.
+    #[must_use]
+    pub fn check(&self, report: &PositionReport) -> Option<MovementAlert> {
+        if self.require_fix && !report.fix_status {
+            return None;
+        }
+        self.threshold
+            .exceeded_by(report)
+            .map(|value| MovementAlert {
+                alert_id: self.id.clone(),
+                threshold: self.threshold.clone(),
+                value,
+                report: report.clone(),
+            })
+    }
+}
+
+fn default_require_fix() -> bool {
+    true
+}
+
+/// A raised movement alert, with the position report that triggered it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct MovementAlert {
+    /// The identifier of the alert configuration that was raised.
+    pub alert_id: String,
+
+    /// The threshold that was exceeded.
+    pub threshold: MovementThreshold,
+
+    /// The measured speed in meters per second or distance in meters.
+    pub value: f64,
+
+    /// The position report that triggered the alert.
+    pub report: PositionReport,
+}
@@ -0,0 +1,93 @@
+use super::{GeoPoint, TimedPosition};
+use serde::{Deserialize, Serialize};
+
+/// Accumulates travelled distance from successive position reports, eg: trip distance.
+/// Jumps implying a speed over `max_speed` are rejected as outliers.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct Odometer {
+    /// The total accumulated distance in meters.
+    pub distance: f64,
+
+    /// The maximum plausible speed in meters per second between two reports.
+    pub max_speed: f64,
+
+    /// The amount of reports rejected as outliers.
+    #[serde(default)]
+    pub rejected: u64,
+
+    /// The last accepted point.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_point: Option<GeoPoint>,
+
+    /// When the last accepted point was received, as a Unix timestamp in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_at: Option<u64>,
+}
+impl Odometer {
+    /// Create a new odometer with a maximum plausible speed in meters per second.
+    #[must_use]
+    pub const fn new(max_speed: f64) -> Self {
+        Self {
+            distance: 0.0,
+            max_speed,
+            rejected: 0,
+            last_point: None,
+            last_at: None,
+        }
+    }
+
+    /// Reset the distance and last point, keeping the maximum speed.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.max_speed);
+    }
+
+    /// Add a position to the odometer, returning the distance added in meters.
+    /// Reports without a fix or older than the last accepted point are ignored,
+    /// and reports implying a speed over `max_speed` are counted as rejected.
+    pub fn push(&mut self, position: &TimedPosition) -> f64 {
+        if !position.report.fix_status {
+            return 0.0;
+        }
+        let Some(point) = position.report.point() else {
+            return 0.0;
+        };
+
+        let added = match (self.last_point, self.last_at) {
+            (Some(last_point), Some(last_at)) => {
+                if position.received_at < last_at {
+                    return 0.0;
+                }
+
+                let distance = last_point.distance_to(&point);
+                #[allow(clippy::cast_precision_loss)]
+                let elapsed = (position.received_at - last_at) as f64 / 1000.0;
+                if distance > self.max_speed * elapsed {
+                    self.rejected += 1;
+                    return 0.0;
+                }
+                distance
+            }
+            _ => 0.0,
+        };
+
+        self.distance += added;
+        self.last_point = Some(point);
+        self.last_at = Some(position.received_at);
+        added
+    }
+}
+impl Default for Odometer {
+    /// Create an odometer rejecting jumps over 70m/s (~250km/h).
+    fn default() -> Self {
+        Self::new(70.0)
+    }
+}
@@ -0,0 +1,172 @@
+use super::{GeoPoint, PositionReport, segment_distance};
+use alloc::{vec, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+/// The smallest latitude and longitude range containing a set of points.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct BoundingBox {
+    /// The south-west corner (minimum latitude and longitude).
+    pub min: GeoPoint,
+
+    /// The north-east corner (maximum latitude and longitude).
+    pub max: GeoPoint,
+}
+impl BoundingBox {
+    /// Check if a point lies within the bounding box.
+    #[must_use]
+    pub fn contains(&self, point: &GeoPoint) -> bool {
+        (self.min.latitude..=self.max.latitude).contains(&point.latitude)
+            && (self.min.longitude..=self.max.longitude).contains(&point.longitude)
+    }
+}
+
+/// An ordered series of position reports, eg: a recorded journey.
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(as = "Vec<PositionReport>"))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[serde(transparent)]
+pub struct Track {
+    /// The position reports, in the order they were received.
+    pub reports: Vec<PositionReport>,
+}
+impl Track {
+    /// Create a new track from a series of position reports.
+    #[must_use]
+    pub fn new(reports: Vec<PositionReport>) -> Self {
+        Self { reports }
+    }
+
+    /// Append a position report to the end of the track.
+    pub fn push(&mut self, report: PositionReport) {
+        self.reports.push(report);
+    }
+
+    /// Iterate over the points of all reports that have a position.
+    pub fn points(&self) -> impl Iterator<Item = GeoPoint> + '_ {
+        self.reports.iter().filter_map(PositionReport::point)
+    }
+
+    /// Get the total distance travelled in meters, skipping reports without a position.
+    #[must_use]
+    pub fn total_distance(&self) -> f64 {
+        let mut points = self.points();
+        let Some(mut previous) = points.next() else {
+            return 0.0;
+        };
+        points
+            .map(|point| {
+                let distance = previous.distance_to(&point);
+                previous = point;
+                distance
+            })
+            .sum()
+    }
+
+    /// Get the time between the first and last timestamped reports.
+    /// Returns None if there are fewer than two reports with a valid UTC time.
+    #[must_use]
+    pub fn duration(&self) -> Option<core::time::Duration> {
+        let first = self
+            .reports
+            .iter()
+            .find_map(PositionReport::utc_time_millis)?;
+        let last = self
+            .reports
+            .iter()
+            .rev()
+            .find_map(PositionReport::utc_time_millis)?;
+        Some(core::time::Duration::from_millis(last.checked_sub(first)?))
+    }
+
+    /// Get the bounding box of all reports with a position.
+    /// Returns None if no reports have a position.
+    #[must_use]
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let mut points = self.points();
+        let first = points.next()?;
+        Some(points.fold(
+            BoundingBox {
+                min: first,
+                max: first,
+            },
+            |bounds, point| BoundingBox {
+                min: GeoPoint::new(
+                    bounds.min.latitude.min(point.latitude),
+                    bounds.min.longitude.min(point.longitude),
+                ),
+                max: GeoPoint::new(
+                    bounds.max.latitude.max(point.latitude),
+                    bounds.max.longitude.max(point.longitude),
+                ),
+            },
+        ))
+    }
+
+    /// Simplify the track to at most `max_points` reports using Douglas-Peucker,
+    /// repeatedly keeping the report furthest from the simplified line until the
+    /// limit is reached. Reports without a position are dropped, and the first
+    /// and last positions are always kept (when `max_points` is at least 2).
+    #[must_use]
+    pub fn simplify(&self, max_points: usize) -> Track {
+        let reports: Vec<(&PositionReport, GeoPoint)> = self
+            .reports
+            .iter()
+            .filter_map(|report| Some((report, report.point()?)))
+            .collect();
+        if reports.len() <= max_points {
+            return Track::new(reports.into_iter().map(|(r, _)| r.clone()).collect());
+        }
+        if max_points < 2 {
+            return Track::new(
+                reports
+                    .iter()
+                    .take(max_points)
+                    .map(|(r, _)| (*r).clone())
+                    .collect(),
+            );
+        }
+
+        // Indexes of kept reports, always sorted.
+        let mut kept = vec![0, reports.len() - 1];
+        while kept.len() < max_points {
+            let furthest = kept
+                .windows(2)
+                .filter_map(|segment| {
+                    let (start, end) = (reports[segment[0]].1, reports[segment[1]].1);
+                    (segment[0] + 1..segment[1])
+                        .map(|i| (i, segment_distance(&start, &end, &reports[i].1)))
+                        .max_by(|a, b| a.1.total_cmp(&b.1))
+                })
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+
+            match furthest {
+                Some((index, _)) => {
+                    let position = kept.partition_point(|&k| k < index);
+                    kept.insert(position, index);
+                }
+                None => break,
+            }
+        }
+        Track::new(kept.into_iter().map(|i| reports[i].0.clone()).collect())
+    }
+}
+impl From<Vec<PositionReport>> for Track {
+    fn from(reports: Vec<PositionReport>) -> Self {
+        Track::new(reports)
+    }
+}
@@ -0,0 +1,206 @@
+use super::{GeoPoint, PositionReport};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use serde::{Deserialize, Serialize};
+
+/// WGS84 semi-major axis in meters.
+const WGS84_A: f64 = 6_378_137.0;
+
+/// WGS84 flattening.
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
+/// UTM central meridian scale factor.
+const SCALE: f64 = 0.9996;
+
+/// False easting applied to every zone.
+const FALSE_EASTING: f64 = 500_000.0;
+
+/// False northing applied in the southern hemisphere.
+const FALSE_NORTHING: f64 = 10_000_000.0;
+
+/// Latitude band letters from 80°S, each covering 8° (X covers 12°).
+const BANDS: &[u8] = b"CDEFGHJKLMNPQRSTUVWX";
+
+/// A Universal Transverse Mercator grid position on the WGS84 ellipsoid.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct Utm {
+    /// The longitude zone (1 - 60).
+    pub zone: u8,
+
+    /// The latitude band letter (C - X, excluding I and O).
+    pub band: char,
+
+    /// Easting in meters.
+    pub easting: f64,
+
+    /// Northing in meters.
+    pub northing: f64,
+}
+impl Utm {
+    /// Check if the position is in the northern hemisphere.
+    #[must_use]
+    pub fn is_northern(&self) -> bool {
+        self.band >= 'N'
+    }
+
+    /// Convert a point to UTM, using the Krüger series for millimeter level accuracy.
+    /// Returns None outside the UTM latitude limits (80°S - 84°N).
+    #[must_use]
+    pub fn from_point(point: &GeoPoint) -> Option<Self> {
+        if !(-80.0..=84.0).contains(&point.latitude) {
+            return None;
+        }
+
+        let (zone, band) = zone_and_band(point.latitude, point.longitude);
+        let n = WGS84_F / (2.0 - WGS84_F);
+        let (alpha, _, _) = series(n);
+        let lat = point.latitude.to_radians();
+        let lon = (point.longitude - central_meridian(zone)).to_radians();
+
+        let e = 2.0 * n.sqrt() / (1.0 + n);
+        let t = (lat.sin().atanh() - e * (e * lat.sin()).atanh()).sinh();
+        let xi_p = t.atan2(lon.cos());
+        let eta_p = (lon.sin() / t.hypot(1.0)).atanh();
+
+        let (mut xi, mut eta) = (xi_p, eta_p);
+        for (j, a) in (1..).zip(alpha) {
+            let j = f64::from(j) * 2.0;
+            xi += a * (j * xi_p).sin() * (j * eta_p).cosh();
+            eta += a * (j * xi_p).cos() * (j * eta_p).sinh();
+        }
+
+        let k = SCALE * rectifying_radius(n);
+        let northing = k * xi
+            + if point.latitude < 0.0 {
+                FALSE_NORTHING
+            } else {
+                0.0
+            };
+        Some(Self {
+            zone,
+            band,
+            easting: FALSE_EASTING + k * eta,
+            northing,
+        })
+    }
+
+    /// Convert back to a latitude and longitude point.
+    #[must_use]
+    pub fn to_point(&self) -> GeoPoint {
+        let n = WGS84_F / (2.0 - WGS84_F);
+        let (_, beta, delta) = series(n);
+        let k = SCALE * rectifying_radius(n);
+        let northing = if self.is_northern() {
+            self.northing
+        } else {
+            self.northing - FALSE_NORTHING
+        };
+
+        let xi = northing / k;
+        let eta = (self.easting - FALSE_EASTING) / k;
+        let (mut xi_p, mut eta_p) = (xi, eta);
+        for (j, b) in (1..).zip(beta) {
+            let j = f64::from(j) * 2.0;
+            xi_p -= b * (j * xi).sin() * (j * eta).cosh();
+            eta_p -= b * (j * xi).cos() * (j * eta).sinh();
+        }
+
+        let chi = (xi_p.sin() / eta_p.cosh()).asin();
+        let mut lat = chi;
+        for (j, d) in (1..).zip(delta) {
+            lat += d * (f64::from(j) * 2.0 * chi).sin();
+        }
+        let lon = eta_p.sinh().atan2(xi_p.cos());
+        GeoPoint::new(
+            lat.to_degrees(),
+            central_meridian(self.zone) + lon.to_degrees(),
+        )
+    }
+}
+impl core::fmt::Display for Utm {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}{} {:.0} {:.0}",
+            self.zone, self.band, self.easting, self.northing
+        )
+    }
+}
+
+impl GeoPoint {
+    /// Convert the point to UTM. Returns None outside 80°S - 84°N.
+    #[must_use]
+    pub fn to_utm(&self) -> Option<Utm> {
+        Utm::from_point(self)
+    }
+}
+
+impl PositionReport {
+    /// Convert the reported position to UTM.
+    /// Returns None if there is no position or it is outside 80°S - 84°N.
+    #[must_use]
+    pub fn to_utm(&self) -> Option<Utm> {
+        self.point()?.to_utm()
+    }
+}
+
+/// Get the zone and band for a point, including the Norway and Svalbard exceptions.
+fn zone_and_band(latitude: f64, longitude: f64) -> (u8, char) {
+    let longitude = crate::gnss::rem_euclid(longitude + 540.0, 360.0) - 180.0;
+
+    // Always within 1 - 60 (or 0 - 19 for the band) after clamping, so the casts cannot truncate.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let mut zone = (((longitude + 180.0) / 6.0).floor() as u8 % 60) + 1;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let band = BANDS[(((latitude + 80.0) / 8.0).floor() as usize).min(BANDS.len() - 1)] as char;
+
+    if band == 'V' && zone == 31 && longitude >= 3.0 {
+        zone = 32;
+    }
+    if band == 'X' && (0.0..42.0).contains(&longitude) {
+        zone = match longitude {
+            l if l < 9.0 => 31,
+            l if l < 21.0 => 33,
+            l if l < 33.0 => 35,
+            _ => 37,
+        };
+    }
+    (zone, band)
+}
+
+/// Get the central meridian in degrees of a zone.
+fn central_meridian(zone: u8) -> f64 {
+    f64::from(zone) * 6.0 - 183.0
+}
+
+/// Get the radius of the rectifying sphere for the third flattening.
+fn rectifying_radius(n: f64) -> f64 {
+    WGS84_A / (1.0 + n) * (1.0 + n.powi(2) / 4.0 + n.powi(4) / 64.0)
+}
+
+/// Get the forward (alpha), inverse (beta) and latitude (delta) Krüger series coefficients.
+fn series(n: f64) -> ([f64; 3], [f64; 3], [f64; 3]) {
+    let (n2, n3) = (n.powi(2), n.powi(3));
+    (
+        [
+            n / 2.0 - 2.0 * n2 / 3.0 + 5.0 * n3 / 16.0,
+            13.0 * n2 / 48.0 - 3.0 * n3 / 5.0,
+            61.0 * n3 / 240.0,
+        ],
+        [
+            n / 2.0 - 2.0 * n2 / 3.0 + 37.0 * n3 / 96.0,
+            n2 / 48.0 + n3 / 15.0,
+            17.0 * n3 / 480.0,
+        ],
+        [
+            2.0 * n - 2.0 * n2 / 3.0 - 2.0 * n3,
+            7.0 * n2 / 3.0 - 8.0 * n3 / 5.0,
+            56.0 * n3 / 15.0,
+        ],
+    )
+}
@@ -0,0 +1,86 @@
+//! GraphQL output types, for exposing messages, events and device info from a gateway.
+//!
+//! Structs and unit enums derive `SimpleObject` and `Enum` directly. `SmsMessage` and `Event`
+//! have inherent methods that share names with their GraphQL fields, so their objects are
+//! implemented here with renamed resolvers. `Event` has payloads of mixed shapes, so it's
+//! exposed as an object with its kind, common fields and JSON data.
+
+use crate::events::{Event, EventKind};
+use crate::sms::SmsMessage;
+use async_graphql::{Json, Object};
+
+#[Object]
+impl SmsMessage {
+    /// Unique identifier for the message.
+    async fn message_id(&self) -> Option<i64> {
+        self.message_id
+    }
+
+    /// The phone number associated with this message.
+    async fn phone_number(&self) -> &str {
+        &self.phone_number
+    }
+
+    /// The actual text content of the message.
+    async fn message_content(&self) -> &str {
+        &self.message_content
+    }
+
+    /// Reference number assigned by the modem, only present for outgoing messages.
+    async fn message_reference(&self) -> Option<u8> {
+        self.message_reference
+    }
+
+    /// Whether this message was sent (true) or received (false).
+    async fn is_outgoing(&self) -> bool {
+        self.is_outgoing
+    }
+
+    /// Unix timestamp in seconds when the message was created.
+    #[graphql(name = "createdAt")]
+    async fn created_at_seconds(&self) -> Option<u32> {
+        self.created_at
+    }
+
+    /// Unix timestamp in seconds when the message was completed/delivered.
+    async fn completed_at(&self) -> Option<u32> {
+        self.completed_at
+    }
+
+    /// Service message center delivery status.
+    async fn status(&self) -> Option<u8> {
+        self.status
+    }
+}
+
+#[Object]
+impl Event {
+    /// The kind of event.
+    async fn kind(&self) -> EventKind {
+        EventKind::from(self)
+    }
+
+    /// The phone number the event relates to, if any.
+    #[graphql(name = "phoneNumber")]
+    async fn event_phone_number(&self) -> Option<&str> {
+        self.phone_number()
+    }
+
+    /// The message, for incoming and outgoing message events.
+    async fn message(&self) -> Option<&SmsMessage> {
+        match self {
+            Event::IncomingMessage(message) | Event::OutgoingMessage { message, .. } => {
+                Some(message)
+            }
+            _ => None,
+        }
+    }
+
+    /// The event payload as JSON, in the same shape as the `data` of a webhook event.
+    async fn data(&self) -> Json<serde_json::Value> {
+        Json(
+            serde_json::to_value(self)
+                .map_or(serde_json::Value::Null, |mut value| value["data"].take()),
+        )
+    }
+}
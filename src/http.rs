@@ -1,13 +1,20 @@
 //! HTTP interface related request/response types.
 
+use crate::validate::{Validate, ValidationError};
 use serde::{Deserialize, Serialize};
 
+/// The largest page size a server accepts in `HttpPaginationOptions`.
+pub const MAX_PAGE_LIMIT: u64 = 1000;
+
 /// HTTP pagination options allow for lazy reading of large sets of data,
 /// for example if thousands of messages have been sent and received from
 /// a phone number it would be impractical to request all of them at the
 /// same time, instead it can be read in shorter pages using limit+offset.
 /// This is applied at the server level when requesting data from database.
 #[derive(Serialize, PartialEq, Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
 pub struct HttpPaginationOptions {
     /// The maximum amount of return values.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -59,37 +66,29 @@ impl HttpPaginationOptions {
         }
     }
 }
-
-/// Response returned after sending an SMS message.
-#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
-pub struct HttpSmsSendResponse {
-    /// The unique ID assigned to the already sent message.
-    pub message_id: i64,
-
-    /// Reference ID for tracking the message.
-    pub reference_id: u8,
-}
-
-/// Combine an outgoing message and send response into a dummy `SmsStoredMessage`.
-impl From<(crate::sms::SmsOutgoingMessage, HttpSmsSendResponse)> for crate::sms::SmsMessage {
-    fn from(
-        value: (crate::sms::SmsOutgoingMessage, HttpSmsSendResponse),
-    ) -> crate::sms::SmsMessage {
-        crate::sms::SmsMessage {
-            message_id: Some(value.1.message_id),
-            phone_number: value.0.to,
-            message_content: value.0.content,
-            message_reference: Some(value.1.reference_id),
-            is_outgoing: true,
-            status: None,
-            created_at: None,
-            completed_at: None,
+impl Validate for HttpPaginationOptions {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self.limit {
+            Some(0) => Err(ValidationError::new("limit", "must be greater than zero")),
+            Some(limit) if limit > MAX_PAGE_LIMIT => Err(ValidationError::new(
+                "limit",
+                format!("must be at most {MAX_PAGE_LIMIT}"),
+            )),
+            _ => Ok(()),
         }
     }
 }
 
+/// Response returned after sending an SMS message.
+pub type HttpSmsSendResponse = crate::sms::SmsSendResponse;
+
 /// Network registration status of the modem.
-#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::SimpleObject))]
 pub struct HttpModemNetworkStatusResponse {
     /// Registration status code (0=not registered, 1=registered home, 5=registered roaming).
     pub registration: u8,
@@ -99,17 +98,15 @@ pub struct HttpModemNetworkStatusResponse {
 }
 
 /// Signal strength information from the modem.
-#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
-pub struct HttpModemSignalStrengthResponse {
-    /// Received Signal Strength Indicator (0-31, 99=unknown).
-    pub rssi: u8,
-
-    /// Bit Error Rate (0-7, 99=unknown).
-    pub ber: u8,
-}
+pub type HttpModemSignalStrengthResponse = crate::modem::SignalStrength;
 
 /// Network operator information from the modem.
-#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::SimpleObject))]
 pub struct HttpModemNetworkOperatorResponse {
     /// Operator selection status (0=automatic, 1=manual).
     pub status: u8,
@@ -122,20 +119,16 @@ pub struct HttpModemNetworkOperatorResponse {
 }
 
 /// Battery status information from the modem.
-#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
-pub struct HttpModemBatteryLevelResponse {
-    /// Battery status (0=not charging, 1=charging, 2=no battery).
-    pub status: u8,
-
-    /// Battery charge level percentage (0-100).
-    pub charge: u8,
-
-    /// Battery voltage in volts.
-    pub voltage: f32,
-}
-
-/// The raw `DeviceInfoResponse` with raw values.
-#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub type HttpModemBatteryLevelResponse = crate::modem::BatteryLevel;
+
+/// Device info response, with each value packed into a proper optional response.
+/// The legacy tuple array form of each value is also accepted when deserializing.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::SimpleObject))]
 pub struct HttpSmsDeviceInfoResponse {
     /// SMS API version string, including features.
     pub version: String,
@@ -146,74 +139,642 @@ pub struct HttpSmsDeviceInfoResponse {
     /// The name of the cellular service provider
     pub service_provider: Option<String>,
 
-    /// Network operator information as (code1, code2, `operator_name`)
-    pub network_operator: Option<(u8, u8, String)>,
-
-    /// Current network connection status as (`status_code`, `strength_indicator`)
-    pub network_status: Option<(u8, u8)>,
-
-    /// Battery information as (`level_percentage`, `charging_status`, voltage)
-    pub battery: Option<(u8, u8, f32)>,
-
-    /// Signal strength information as (`strength_level`, `quality_indicator`)
-    pub signal: Option<(u8, u8)>,
-}
-
-/// Formatted device info response, with each value packed into a proper optional response.
-#[derive(Deserialize, PartialEq, Debug, Clone)]
-pub struct HttpSmsDeviceInfoData {
-    /// SMS API version string, including features.
-    pub version: String,
-
-    /// The phone number associated with the SMS device
-    pub phone_number: Option<String>,
-
-    /// The name of the cellular service provider
-    pub service_provider: Option<String>,
-
     /// Detailed network operator information and capabilities
+    #[serde(
+        default,
+        deserialize_with = "deserialize_device_info_field::<_, _, (u8, u8, String)>"
+    )]
     pub network_operator: Option<HttpModemNetworkOperatorResponse>,
 
     /// Current network connection status and diagnostics
+    #[serde(
+        default,
+        deserialize_with = "deserialize_device_info_field::<_, _, (u8, u8)>"
+    )]
     pub network_status: Option<HttpModemNetworkStatusResponse>,
 
     /// Battery level, charging state, and power metrics
+    #[serde(
+        default,
+        deserialize_with = "deserialize_device_info_field::<_, _, (u8, u8, f32)>"
+    )]
     pub battery: Option<HttpModemBatteryLevelResponse>,
 
     /// Signal strength measurements and quality indicators
+    #[serde(
+        default,
+        deserialize_with = "deserialize_device_info_field::<_, _, (u8, u8)>"
+    )]
     pub signal: Option<HttpModemSignalStrengthResponse>,
 }
-impl From<HttpSmsDeviceInfoResponse> for HttpSmsDeviceInfoData {
-    fn from(value: HttpSmsDeviceInfoResponse) -> HttpSmsDeviceInfoData {
-        HttpSmsDeviceInfoData {
-            version: value.version,
-            phone_number: value.phone_number,
-            service_provider: value.service_provider,
-            network_operator: value
-                .network_operator
-                .map(|v| HttpModemNetworkOperatorResponse {
-                    status: v.0,
-                    format: v.1,
-                    operator: v.2,
-                }),
-            network_status: value
-                .network_status
-                .map(|v| HttpModemNetworkStatusResponse {
-                    registration: v.0,
-                    technology: v.1,
-                }),
-            battery: value.battery.map(|v| HttpModemBatteryLevelResponse {
-                status: v.0,
-                charge: v.1,
-                voltage: v.2,
-            }),
-            signal: value.signal.map(|v| HttpModemSignalStrengthResponse {
-                rssi: v.0,
-                ber: v.1,
-            }),
+
+/// Formatted device info response, now identical to `HttpSmsDeviceInfoResponse`.
+#[deprecated(
+    note = "use `HttpSmsDeviceInfoResponse` directly, it now contains the structured values"
+)]
+pub type HttpSmsDeviceInfoData = HttpSmsDeviceInfoResponse;
+
+/// A device info value, either as its structured object or as the legacy tuple array.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DeviceInfoField<T, L> {
+    Struct(T),
+    Legacy(L),
+}
+
+/// Deserialize an optional device info value, accepting the legacy tuple array form.
+fn deserialize_device_info_field<'de, D, T, L>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+    L: Deserialize<'de> + Into<T>,
+{
+    Ok(
+        Option::<DeviceInfoField<T, L>>::deserialize(deserializer)?.map(|field| match field {
+            DeviceInfoField::Struct(value) => value,
+            DeviceInfoField::Legacy(legacy) => legacy.into(),
+        }),
+    )
+}
+
+/// Convert from the legacy (status, format, operator) tuple.
+impl From<(u8, u8, String)> for HttpModemNetworkOperatorResponse {
+    fn from(value: (u8, u8, String)) -> Self {
+        HttpModemNetworkOperatorResponse {
+            status: value.0,
+            format: value.1,
+            operator: value.2,
+        }
+    }
+}
+
+/// Convert from the legacy (registration, technology) tuple.
+impl From<(u8, u8)> for HttpModemNetworkStatusResponse {
+    fn from(value: (u8, u8)) -> Self {
+        HttpModemNetworkStatusResponse {
+            registration: value.0,
+            technology: value.1,
         }
     }
 }
 
 /// Used in latest-numbers return value, as a number and friendly name.
 pub type LatestNumberFriendlyNamePair = (String, Option<String>);
+
+/// Request to restart the modem. The modem will go through the `ShuttingDown`
+/// and `Startup` states, which are also sent as `ModemStatusUpdate` events.
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct HttpModemRestartRequest {
+    /// Should the modem be power-cycled rather than soft reset with an AT command?
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hard: Option<bool>,
+}
+impl HttpModemRestartRequest {
+    /// Set the hard reset state for the request.
+    #[must_use]
+    pub fn with_hard(mut self, hard: bool) -> Self {
+        self.hard = Some(hard);
+        self
+    }
+}
+
+/// Response returned after requesting a modem restart.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct HttpModemRestartResponse {
+    /// The modem state at the time the restart was accepted.
+    pub status: crate::modem::ModemStatusUpdateState,
+}
+
+/// Request to change the network operator selection.
+/// The server responds with the resulting `HttpModemNetworkOperatorResponse`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct HttpModemSetOperatorRequest {
+    /// How the operator should be selected.
+    pub mode: crate::modem::ModemOperatorSelectionMode,
+
+    /// The numeric operator code (MCC+MNC, eg: 23410), required for manual selection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operator: Option<String>,
+}
+impl HttpModemSetOperatorRequest {
+    /// Create a request to let the modem automatically select an operator.
+    #[must_use]
+    pub fn automatic() -> Self {
+        Self {
+            mode: crate::modem::ModemOperatorSelectionMode::Automatic,
+            operator: None,
+        }
+    }
+
+    /// Create a request to manually register with a specific numeric operator code.
+    #[must_use]
+    pub fn manual(operator: impl Into<String>) -> Self {
+        Self {
+            mode: crate::modem::ModemOperatorSelectionMode::Manual,
+            operator: Some(operator.into()),
+        }
+    }
+
+    /// Create a request to manually register with a specific numeric operator code,
+    /// falling back to automatic selection if it fails.
+    #[must_use]
+    pub fn manual_with_fallback(operator: impl Into<String>) -> Self {
+        Self {
+            mode: crate::modem::ModemOperatorSelectionMode::ManualWithFallback,
+            operator: Some(operator.into()),
+        }
+    }
+
+    /// Create a request to deregister from the network.
+    #[must_use]
+    pub fn deregister() -> Self {
+        Self {
+            mode: crate::modem::ModemOperatorSelectionMode::Deregister,
+            operator: None,
+        }
+    }
+
+    /// Check the request, returning it if it's valid.
+    ///
+    /// # Errors
+    /// Returns a `ValidationError` for the first invalid field.
+    pub fn build(self) -> Result<Self, ValidationError> {
+        self.validate()?;
+        Ok(self)
+    }
+}
+impl Validate for HttpModemSetOperatorRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        use crate::modem::ModemOperatorSelectionMode;
+
+        match (&self.mode, &self.operator) {
+            (
+                ModemOperatorSelectionMode::Manual | ModemOperatorSelectionMode::ManualWithFallback,
+                None,
+            ) => Err(ValidationError::new(
+                "operator",
+                "is required for manual selection",
+            )),
+            (_, Some(operator))
+                if !(5..=6).contains(&operator.len())
+                    || !operator.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                Err(ValidationError::new(
+                    "operator",
+                    "must be a 5 or 6 digit MCC+MNC code",
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Request to change the preferred network technology mode.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct HttpModemSetNetworkModeRequest {
+    /// The network mode to use.
+    pub mode: crate::modem::ModemNetworkMode,
+}
+impl HttpModemSetNetworkModeRequest {
+    /// Create a request to use a network mode.
+    #[must_use]
+    pub fn new(mode: crate::modem::ModemNetworkMode) -> Self {
+        Self { mode }
+    }
+}
+
+/// The network technology mode currently in use by the modem.
+#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct HttpModemNetworkModeResponse {
+    /// The active network mode.
+    pub mode: crate::modem::ModemNetworkMode,
+}
+
+/// Request to send a raw AT command directly to the modem. This bypasses all
+/// server side handling, so the server only accepts it for clients that have
+/// been granted the raw AT command scope.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
+pub struct HttpModemSendAtRequest {
+    /// The full AT command to send, eg: `AT+CSQ`.
+    pub command: String,
+
+    /// A timeout in seconds to wait for the modem response.
+    /// If one is not set, the default timeout is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u32>,
+}
+impl HttpModemSendAtRequest {
+    /// Create a new raw AT command request with the default timeout.
+    #[must_use]
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            timeout: None,
+        }
+    }
+
+    /// Set a request timeout value.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: u32) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Check the request, returning it if it's valid.
+    ///
+    /// # Errors
+    /// Returns a `ValidationError` for the first invalid field.
+    pub fn build(self) -> Result<Self, ValidationError> {
+        self.validate()?;
+        Ok(self)
+    }
+}
+impl Validate for HttpModemSendAtRequest {
+    /// The command must start with `AT` and be a single line, so it can't smuggle in a second command.
+    fn validate(&self) -> Result<(), ValidationError> {
+        if !self
+            .command
+            .get(..2)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("AT"))
+        {
+            return Err(ValidationError::new("command", "must start with 'AT'"));
+        }
+        if self.command.contains(['\r', '\n']) {
+            return Err(ValidationError::new("command", "must be a single line"));
+        }
+        crate::validate::timeout(self.timeout)
+    }
+}
+
+/// Response returned from the modem after a raw AT command.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
+pub struct HttpModemSendAtResponse {
+    /// Whether the modem terminated the response with `OK`.
+    pub success: bool,
+
+    /// The raw response lines returned by the modem, excluding the final result code.
+    pub response: Vec<String>,
+}
+
+/// Request to send a USSD code, or reply to an open USSD session.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct HttpUssdSendRequest {
+    /// The USSD code to dial (eg: `*100#`), or the reply text for an open session.
+    pub code: String,
+
+    /// The open session to reply to. If not set, a new session is started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<crate::ussd::UssdSessionId>,
+
+    /// A timeout in seconds to wait for the network response.
+    /// If one is not set, the default timeout is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u32>,
+}
+impl HttpUssdSendRequest {
+    /// Create a request that starts a new USSD session.
+    #[must_use]
+    pub fn new(code: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            session_id: None,
+            timeout: None,
+        }
+    }
+
+    /// Create a request replying to an already open USSD session.
+    #[must_use]
+    pub fn reply(session_id: crate::ussd::UssdSessionId, code: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            session_id: Some(session_id),
+            timeout: None,
+        }
+    }
+
+    /// Set a request timeout value.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: u32) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Check the request, returning it if it's valid.
+    ///
+    /// # Errors
+    /// Returns a `ValidationError` for the first invalid field.
+    pub fn build(self) -> Result<Self, ValidationError> {
+        self.validate()?;
+        Ok(self)
+    }
+}
+impl Validate for HttpUssdSendRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::not_blank("code", &self.code)?;
+        crate::validate::timeout(self.timeout)
+    }
+}
+
+/// Response returned after sending a USSD code.
+pub type HttpUssdResponse = crate::ussd::UssdResponse;
+
+/// How long the server keeps stored messages and delivery reports before purging them.
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct HttpRetentionPolicy {
+    /// Maximum age in seconds of stored messages, or None to keep forever.
+    pub max_age: Option<u64>,
+
+    /// Maximum amount of stored messages, or None for no limit.
+    pub max_messages: Option<u64>,
+}
+
+/// The current server configuration.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct HttpServerConfigResponse {
+    /// Server features that are enabled, eg: `http`, `websocket`, `gnss`.
+    pub features: Vec<String>,
+
+    /// The amount of configured webhooks.
+    pub webhook_count: u32,
+
+    /// The storage retention policy.
+    pub retention: HttpRetentionPolicy,
+
+    /// The relative validity period used for outgoing messages that don't set one.
+    pub default_validity_period: u8,
+
+    /// The maximum allowed outgoing message content length in characters.
+    pub max_message_length: u32,
+
+    /// The GNSS position report interval in seconds, or None if GNSS is disabled.
+    pub gnss_poll_interval: Option<u32>,
+}
+impl HttpServerConfigResponse {
+    /// Check if a server feature is enabled.
+    #[must_use]
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f == feature)
+    }
+}
+
+/// Request to download assisted GNSS (XTRA) data and inject it into the modem,
+/// reducing the time to first fix after a cold start.
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct HttpGnssAssistanceRequest {
+    /// Download new data even if the currently injected data is still valid.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+
+    /// Override the assistance data server URL, otherwise the server default is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+impl HttpGnssAssistanceRequest {
+    /// Set the force download state.
+    #[must_use]
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = Some(force);
+        self
+    }
+
+    /// Set the assistance data server URL.
+    #[must_use]
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Check the request, returning it if it's valid.
+    ///
+    /// # Errors
+    /// Returns a `ValidationError` for the first invalid field.
+    pub fn build(self) -> Result<Self, ValidationError> {
+        self.validate()?;
+        Ok(self)
+    }
+}
+impl Validate for HttpGnssAssistanceRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match &self.url {
+            Some(url) => crate::validate::http_url("url", url),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The state of assisted GNSS data in the modem.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum GnssAssistanceStatus {
+    /// No assistance data has been downloaded.
+    NotDownloaded,
+
+    /// Assistance data is currently being downloaded.
+    Downloading,
+
+    /// Assistance data has been injected into the modem and is valid.
+    Injected,
+
+    /// The injected assistance data has expired.
+    Expired,
+
+    /// The last download or injection failed.
+    Failed,
+}
+impl core::fmt::Display for GnssAssistanceStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            GnssAssistanceStatus::NotDownloaded => "NotDownloaded",
+            GnssAssistanceStatus::Downloading => "Downloading",
+            GnssAssistanceStatus::Injected => "Injected",
+            GnssAssistanceStatus::Expired => "Expired",
+            GnssAssistanceStatus::Failed => "Failed",
+        })
+    }
+}
+
+/// The current assisted GNSS data status.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct HttpGnssAssistanceResponse {
+    /// The assistance data state.
+    pub status: GnssAssistanceStatus,
+
+    /// Unix timestamp when the data was last downloaded.
+    pub downloaded_at: Option<u32>,
+
+    /// Unix timestamp the data is valid from.
+    pub valid_from: Option<u32>,
+
+    /// Unix timestamp the data is valid until.
+    pub valid_until: Option<u32>,
+
+    /// The reason the last download or injection failed, if it did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+impl HttpGnssAssistanceResponse {
+    /// Check if the injected data is valid at a Unix timestamp.
+    #[must_use]
+    pub fn is_valid_at(&self, timestamp: u32) -> bool {
+        self.status == GnssAssistanceStatus::Injected
+            && self.valid_from.is_none_or(|from| timestamp >= from)
+            && self.valid_until.is_some_and(|until| timestamp < until)
+    }
+}
+
+/// The current GNSS receiver configuration.
+#[cfg(feature = "gnss")]
+pub type HttpGnssConfigResponse = crate::gnss::GnssConfig;
+
+/// Request to update the GNSS receiver configuration.
+/// Only the values that are set are changed, and the server responds with
+/// the resulting `HttpGnssConfigResponse`.
+#[cfg(feature = "gnss")]
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct HttpGnssSetConfigRequest {
+    /// Power the GNSS receiver on or off.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// How often position reports are read from the modem, in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report_interval: Option<u32>,
+
+    /// The satellite constellations to use for fixes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constellations: Option<Vec<crate::gnss::Constellation>>,
+
+    /// The minimum amount of satellites used in a fix for it to be reported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_satellites: Option<u8>,
+}
+#[cfg(feature = "gnss")]
+impl HttpGnssSetConfigRequest {
+    /// Set the GNSS receiver power state.
+    #[must_use]
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Set the report interval in seconds.
+    #[must_use]
+    pub fn with_report_interval(mut self, report_interval: u32) -> Self {
+        self.report_interval = Some(report_interval);
+        self
+    }
+
+    /// Set the constellations to use.
+    #[must_use]
+    pub fn with_constellations(mut self, constellations: Vec<crate::gnss::Constellation>) -> Self {
+        self.constellations = Some(constellations);
+        self
+    }
+
+    /// Set the minimum amount of satellites for reporting.
+    #[must_use]
+    pub fn with_min_satellites(mut self, min_satellites: u8) -> Self {
+        self.min_satellites = Some(min_satellites);
+        self
+    }
+
+    /// Check the request, returning it if it's valid.
+    ///
+    /// # Errors
+    /// Returns a `ValidationError` for the first invalid field.
+    pub fn build(self) -> Result<Self, ValidationError> {
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Apply the set values to an existing configuration.
+    pub fn apply_to(&self, config: &mut crate::gnss::GnssConfig) {
+        if let Some(enabled) = self.enabled {
+            config.enabled = enabled;
+        }
+        if let Some(report_interval) = self.report_interval {
+            config.report_interval = report_interval;
+        }
+        if let Some(constellations) = &self.constellations {
+            config.constellations.clone_from(constellations);
+        }
+        if let Some(min_satellites) = self.min_satellites {
+            config.min_satellites = min_satellites;
+        }
+    }
+}
+#[cfg(feature = "gnss")]
+impl Validate for HttpGnssSetConfigRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.report_interval == Some(0) {
+            return Err(ValidationError::new(
+                "report_interval",
+                "must be greater than zero",
+            ));
+        }
+        if self.constellations.as_ref().is_some_and(Vec::is_empty) {
+            return Err(ValidationError::new(
+                "constellations",
+                "must include at least one constellation",
+            ));
+        }
+        Ok(())
+    }
+}
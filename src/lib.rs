@@ -1,15 +1,72 @@
 //! SMS Server and Client shared types.
+//!
+//! The `camel-case` feature renames every serialized field to camelCase, eg: for JavaScript
+//! consumers. It changes the wire format, so it isn't additive: Cargo unifies features across
+//! a build, so one dependency enabling it switches every crate using these types to camelCase,
+//! and their snake case payloads stop deserializing. Only enable it in a final binary that
+//! owns all serialization, never in a library.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 #![deny(unsafe_code)]
 #![warn(clippy::all, clippy::pedantic)]
 
-pub mod events;
+extern crate alloc;
+
+pub mod limits;
 pub mod modem;
+pub mod serde_util;
 pub mod sms;
+pub mod timestamp;
+pub mod validate;
+
+#[cfg(feature = "std")]
+pub mod call;
+
+#[cfg(feature = "std")]
+pub mod compact;
+
+#[cfg(feature = "std")]
+pub mod events;
+
+#[cfg(feature = "std")]
+pub mod mqtt;
+
+#[cfg(feature = "std")]
+pub mod ussd;
 
 #[cfg(feature = "http")]
 pub mod http;
 
+#[cfg(feature = "websocket")]
+pub mod websocket;
+
 #[cfg(feature = "gnss")]
 pub mod gnss;
+
+#[cfg(feature = "cbor")]
+pub mod cbor;
+
+#[cfg(feature = "proto")]
+pub mod proto;
+
+#[cfg(feature = "avro")]
+pub mod avro;
+
+#[cfg(feature = "async-graphql")]
+pub mod graphql;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "pyo3")]
+pub mod python;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "test-strategies")]
+pub mod strategies;
+
+#[cfg(feature = "fake")]
+pub mod fixtures;
@@ -0,0 +1,113 @@
+//! SMS protocol limits from GSM 03.38 and 03.40, so servers and clients share the same thresholds.
+//!
+//! eg: `limits::message_parts("Hello") == 1`
+
+/// The maximum GSM 7-bit septets in a single message.
+pub const MAX_GSM7_SINGLE: usize = 160;
+
+/// The maximum GSM 7-bit septets in each part of a multipart message,
+/// after the concatenation header.
+pub const MAX_GSM7_MULTIPART: usize = 153;
+
+/// The maximum UCS-2 characters in a single message.
+pub const MAX_UCS2_SINGLE: usize = 70;
+
+/// The maximum UCS-2 characters in each part of a multipart message,
+/// after the concatenation header.
+pub const MAX_UCS2_MULTIPART: usize = 67;
+
+/// The maximum amount of parts in a multipart message, as the part count is a single byte.
+pub const MAX_MULTIPART_PARTS: usize = 255;
+
+/// The maximum relative validity period (TP-VP) value, meaning 63 weeks.
+pub const MAX_VALIDITY: u8 = u8::MAX;
+
+/// The relative validity period used when one isn't set, meaning 24 hours.
+pub const DEFAULT_VALIDITY: u8 = 167;
+
+/// The maximum digits in an E.164 phone number, excluding the leading `+`.
+pub const MAX_PHONE_LEN: usize = 15;
+
+/// The GSM 03.38 basic character set in septet order, without the extension table escape.
+const GSM7_BASIC: &str = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?\
+    ¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+
+/// The GSM 03.38 extension table characters, each sent as an escape and a septet.
+const GSM7_EXTENSION: &str = "\u{c}^{}\\[~]|€";
+
+/// Get the amount of GSM 7-bit septets needed to encode content,
+/// or None if it has characters outside of the GSM 7-bit alphabet.
+#[must_use]
+pub fn gsm7_septets(content: &str) -> Option<usize> {
+    content.chars().try_fold(0, |septets, c| {
+        if GSM7_BASIC.contains(c) {
+            Some(septets + 1)
+        } else if GSM7_EXTENSION.contains(c) {
+            Some(septets + 2)
+        } else {
+            None
+        }
+    })
+}
+
+/// Check if content can be sent with the GSM 7-bit alphabet, rather than UCS-2.
+#[must_use]
+pub fn is_gsm7(content: &str) -> bool {
+    gsm7_septets(content).is_some()
+}
+
+/// Get the amount of message parts needed to send content, using GSM 7-bit
+/// when possible and UCS-2 otherwise. Empty content is sent as a single part.
+#[must_use]
+pub fn message_parts(content: &str) -> usize {
+    let (length, single, multipart) = match gsm7_septets(content) {
+        Some(septets) => (septets, MAX_GSM7_SINGLE, MAX_GSM7_MULTIPART),
+        None => (
+            content.encode_utf16().count(),
+            MAX_UCS2_SINGLE,
+            MAX_UCS2_MULTIPART,
+        ),
+    };
+    if length <= single {
+        1
+    } else {
+        length.div_ceil(multipart)
+    }
+}
+
+/// Check if content fits in a single message.
+#[must_use]
+pub fn fits_single(content: &str) -> bool {
+    message_parts(content) == 1
+}
+
+/// Check if content fits in a multipart message.
+#[must_use]
+pub fn fits_multipart(content: &str) -> bool {
+    message_parts(content) <= MAX_MULTIPART_PARTS
+}
+
+/// Check if a phone number has an allowed amount of digits, ignoring a leading `+`.
+#[must_use]
+pub fn is_valid_phone_len(phone_number: &str) -> bool {
+    let digits = phone_number.strip_prefix('+').unwrap_or(phone_number);
+    (1..=MAX_PHONE_LEN).contains(&digits.len())
+}
+
+/// Get how long a relative validity period (TP-VP) value lasts.
+///
+/// - 0 to 143: (value + 1) x 5 minutes, up to 12 hours.
+/// - 144 to 167: 12 hours + (value - 143) x 30 minutes, up to 24 hours.
+/// - 168 to 196: (value - 166) days, up to 30 days.
+/// - 197 to 255: (value - 192) weeks, up to 63 weeks.
+#[must_use]
+pub fn validity_duration(validity: u8) -> core::time::Duration {
+    let value = u64::from(validity);
+    let minutes = match validity {
+        0..=143 => (value + 1) * 5,
+        144..=167 => 12 * 60 + (value - 143) * 30,
+        168..=196 => (value - 166) * 24 * 60,
+        197..=u8::MAX => (value - 192) * 7 * 24 * 60,
+    };
+    core::time::Duration::from_secs(minutes * 60)
+}
@@ -1,9 +1,20 @@
 //! Types used by the SMS server Modem, sent in events.
 
+use alloc::{format, string::String};
 use serde::{Deserialize, Serialize};
 
 /// Represents the current status of the modem.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+/// States added by newer servers are kept as `Other`, rather than failing to deserialize.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
 pub enum ModemStatusUpdateState {
     /// Modem is starting up.
     Startup,
@@ -16,14 +27,263 @@ pub enum ModemStatusUpdateState {
 
     /// Modem is offline and not operational.
     Offline,
+
+    /// A state this version doesn't know, with its serialized name.
+    #[serde(untagged)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(skip))]
+    Other(String),
 }
-impl std::fmt::Display for ModemStatusUpdateState {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for ModemStatusUpdateState {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match self {
             ModemStatusUpdateState::Startup => write!(f, "Startup"),
             ModemStatusUpdateState::Online => write!(f, "Online"),
             ModemStatusUpdateState::ShuttingDown => write!(f, "ShuttingDown"),
             ModemStatusUpdateState::Offline => write!(f, "Offline"),
+            ModemStatusUpdateState::Other(name) => f.write_str(name),
+        }
+    }
+}
+impl core::str::FromStr for ModemStatusUpdateState {
+    type Err = core::convert::Infallible;
+
+    /// Parse a `ModemStatusUpdateState` from its displayed name.
+    /// Names this version doesn't know become `Other`, so this is the inverse of `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "Startup" => ModemStatusUpdateState::Startup,
+            "Online" => ModemStatusUpdateState::Online,
+            "ShuttingDown" => ModemStatusUpdateState::ShuttingDown,
+            "Offline" => ModemStatusUpdateState::Offline,
+            _ => ModemStatusUpdateState::Other(String::from(s)),
+        })
+    }
+}
+
+/// Operator selection mode used when registering to a network (AT+COPS).
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
+pub enum ModemOperatorSelectionMode {
+    /// Let the modem automatically select a network operator.
+    Automatic,
+
+    /// Manually register to a specific network operator.
+    Manual,
+
+    /// Deregister from the network.
+    Deregister,
+
+    /// Attempt manual registration, falling back to automatic if it fails.
+    ManualWithFallback,
+}
+impl core::fmt::Display for ModemOperatorSelectionMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ModemOperatorSelectionMode::Automatic => "Automatic",
+            ModemOperatorSelectionMode::Manual => "Manual",
+            ModemOperatorSelectionMode::Deregister => "Deregister",
+            ModemOperatorSelectionMode::ManualWithFallback => "ManualWithFallback",
+        })
+    }
+}
+impl ModemOperatorSelectionMode {
+    /// Get the AT+COPS mode value.
+    #[must_use]
+    pub const fn to_mode(self) -> u8 {
+        match self {
+            ModemOperatorSelectionMode::Automatic => 0,
+            ModemOperatorSelectionMode::Manual => 1,
+            ModemOperatorSelectionMode::Deregister => 2,
+            ModemOperatorSelectionMode::ManualWithFallback => 4,
+        }
+    }
+}
+
+/// Preferred network technology mode for the modem.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
+pub enum ModemNetworkMode {
+    /// Automatically select the best available technology.
+    Automatic,
+
+    /// Only use GSM (2G) networks.
+    GsmOnly,
+
+    /// Only use WCDMA (3G) networks.
+    WcdmaOnly,
+
+    /// Only use LTE (4G) networks.
+    LteOnly,
+}
+impl core::fmt::Display for ModemNetworkMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ModemNetworkMode::Automatic => "Automatic",
+            ModemNetworkMode::GsmOnly => "GsmOnly",
+            ModemNetworkMode::WcdmaOnly => "WcdmaOnly",
+            ModemNetworkMode::LteOnly => "LteOnly",
+        })
+    }
+}
+
+/// SIM card state, as reported by AT+CPIN.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[non_exhaustive]
+pub enum SimStatus {
+    /// SIM is unlocked and ready.
+    Ready,
+
+    /// SIM is waiting for its PIN.
+    PinRequired,
+
+    /// SIM is locked after too many PIN attempts and is waiting for its PUK.
+    PukRequired,
+
+    /// No SIM is inserted.
+    NotInserted,
+
+    /// SIM is inserted but failed or could not be read.
+    Failure,
+}
+impl SimStatus {
+    /// Returns true if the SIM can be used to send and receive messages.
+    #[must_use]
+    pub const fn is_ready(self) -> bool {
+        matches!(self, SimStatus::Ready)
+    }
+}
+impl TryFrom<&str> for SimStatus {
+    type Error = String;
+
+    /// Convert an AT+CPIN code into a `SimStatus`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "READY" => Ok(SimStatus::Ready),
+            "SIM PIN" => Ok(SimStatus::PinRequired),
+            "SIM PUK" => Ok(SimStatus::PukRequired),
+            "NOT INSERTED" => Ok(SimStatus::NotInserted),
+            "NOT READY" | "SIM ERROR" => Ok(SimStatus::Failure),
+            _ => Err(format!("Invalid SIM status: '{value}'")),
+        }
+    }
+}
+impl core::fmt::Display for SimStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            SimStatus::Ready => write!(f, "Ready"),
+            SimStatus::PinRequired => write!(f, "PinRequired"),
+            SimStatus::PukRequired => write!(f, "PukRequired"),
+            SimStatus::NotInserted => write!(f, "NotInserted"),
+            SimStatus::Failure => write!(f, "Failure"),
+        }
+    }
+}
+
+/// Signal strength information from the modem, as reported by AT+CSQ.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::SimpleObject))]
+pub struct SignalStrength {
+    /// Received Signal Strength Indicator (0-31, 99=unknown).
+    pub rssi: u8,
+
+    /// Bit Error Rate (0-7, 99=unknown).
+    pub ber: u8,
+}
+impl SignalStrength {
+    /// Returns true if the RSSI is known.
+    #[must_use]
+    pub const fn is_known(&self) -> bool {
+        self.rssi <= 31
+    }
+
+    /// Get the RSSI in dBm (-113 to -51), or None if unknown.
+    #[must_use]
+    pub fn dbm(&self) -> Option<i16> {
+        self.is_known().then(|| -113 + 2 * i16::from(self.rssi))
+    }
+}
+
+/// Convert from the legacy (rssi, ber) tuple.
+impl From<(u8, u8)> for SignalStrength {
+    fn from(value: (u8, u8)) -> Self {
+        SignalStrength {
+            rssi: value.0,
+            ber: value.1,
+        }
+    }
+}
+
+/// Battery status information from the modem, as reported by AT+CBC.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::SimpleObject))]
+pub struct BatteryLevel {
+    /// Battery status (0=not charging, 1=charging, 2=no battery).
+    pub status: u8,
+
+    /// Battery charge level percentage (0-100).
+    pub charge: u8,
+
+    /// Battery voltage in volts.
+    pub voltage: f32,
+}
+impl BatteryLevel {
+    /// Returns true if the battery is present and not charging.
+    #[must_use]
+    pub const fn is_discharging(&self) -> bool {
+        self.status == 0
+    }
+}
+
+/// Convert from the legacy (status, charge, voltage) tuple.
+impl From<(u8, u8, f32)> for BatteryLevel {
+    fn from(value: (u8, u8, f32)) -> Self {
+        BatteryLevel {
+            status: value.0,
+            charge: value.1,
+            voltage: value.2,
         }
     }
 }
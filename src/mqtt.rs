@@ -0,0 +1,160 @@
+//! MQTT topic mapping for events, eg: for bridging events into home automation.
+//!
+//! Topics are `{prefix}/{group}[/{subject}][/{leaf}]`, eg: `sms/%2B447700900123/incoming`
+//! or `gnss/position`. Subjects are phone numbers, webhook ids or custom event names,
+//! with `%`, `/`, `+`, `#` and NUL percent-encoded so they are always a single valid segment.
+
+use crate::events::{Event, EventKind};
+use std::fmt::Write;
+
+/// Whether a topic has a subject segment.
+#[derive(Clone, Copy)]
+enum Subject {
+    None,
+    Optional,
+    Required,
+}
+
+/// Get the topic layout of an event kind, as `(group, subject, leaf)`.
+const fn layout(kind: EventKind) -> (&'static str, Subject, Option<&'static str>) {
+    match kind {
+        EventKind::IncomingMessage => ("sms", Subject::Required, Some("incoming")),
+        EventKind::OutgoingMessage => ("sms", Subject::Required, Some("outgoing")),
+        EventKind::DeliveryReport => ("sms", Subject::Required, Some("delivery")),
+        EventKind::IncomingCall => ("call", Subject::Optional, Some("incoming")),
+        EventKind::ModemStatusUpdate => ("modem", Subject::None, Some("status")),
+        EventKind::SimStatusChange => ("modem", Subject::None, Some("sim")),
+        EventKind::SignalStrengthUpdate => ("modem", Subject::None, Some("signal")),
+        EventKind::StorageFull => ("modem", Subject::None, Some("storage_full")),
+        EventKind::BatteryLow => ("modem", Subject::None, Some("battery_low")),
+        EventKind::UssdResponse => ("ussd", Subject::None, Some("response")),
+        EventKind::WebhookDeliveryFailed => ("webhook", Subject::Required, Some("failed")),
+        EventKind::Digest => ("digest", Subject::None, None),
+        EventKind::Custom => ("custom", Subject::Required, None),
+        EventKind::GNSSPositionReport => ("gnss", Subject::None, Some("position")),
+        EventKind::GNSSPowerStateUpdate => ("gnss", Subject::None, Some("power")),
+        EventKind::GNSSFixAcquired => ("gnss", Subject::None, Some("fix")),
+        EventKind::GeofenceTransition => ("gnss", Subject::None, Some("geofence")),
+        EventKind::WebsocketConnectionUpdate => ("websocket", Subject::None, Some("connection")),
+        EventKind::Unknown => ("unknown", Subject::None, None),
+    }
+}
+
+/// The MQTT topic an event is published to.
+#[derive(Eq, PartialEq, Hash, Debug, Clone)]
+pub struct MqttTopic {
+    /// The kind of event published to the topic.
+    pub kind: EventKind,
+
+    /// The phone number, webhook id or custom event name the topic is for, if any.
+    /// This is None for incoming calls where the caller withheld their number.
+    pub subject: Option<String>,
+}
+impl MqttTopic {
+    /// Format the topic under a prefix, eg: `home`. An empty prefix is allowed.
+    #[must_use]
+    pub fn to_topic(&self, prefix: &str) -> String {
+        let (group, _, leaf) = layout(self.kind);
+        let prefix = prefix.trim_end_matches('/');
+
+        let mut segments = Vec::with_capacity(4);
+        if !prefix.is_empty() {
+            segments.push(prefix.to_string());
+        }
+        segments.push(group.to_string());
+        if let Some(subject) = &self.subject {
+            segments.push(encode_segment(subject));
+        }
+        if let Some(leaf) = leaf {
+            segments.push(leaf.to_string());
+        }
+        segments.join("/")
+    }
+
+    /// Parse a topic published under a prefix.
+    /// Returns None if the topic isn't under the prefix or doesn't match an event kind.
+    #[must_use]
+    pub fn parse(prefix: &str, topic: &str) -> Option<Self> {
+        let prefix = prefix.trim_end_matches('/');
+        let rest = if prefix.is_empty() {
+            topic
+        } else {
+            topic.strip_prefix(prefix)?.strip_prefix('/')?
+        };
+        let segments: Vec<&str> = rest.split('/').collect();
+
+        EventKind::ALL
+            .iter()
+            .copied()
+            .chain([EventKind::Unknown])
+            .find_map(|kind| {
+                let (group, subject, leaf) = layout(kind);
+                let (first, remaining) = segments.split_first()?;
+                if *first != group {
+                    return None;
+                }
+                let remaining = match leaf {
+                    Some(leaf) => remaining.strip_suffix(&[leaf])?,
+                    None => remaining,
+                };
+                let subject = match (subject, remaining) {
+                    (Subject::None | Subject::Optional, []) => None,
+                    (Subject::Optional | Subject::Required, [subject]) => {
+                        Some(decode_segment(subject)?)
+                    }
+                    _ => return None,
+                };
+                Some(Self { kind, subject })
+            })
+    }
+}
+impl From<&Event> for MqttTopic {
+    fn from(value: &Event) -> Self {
+        let subject = match value {
+            Event::WebhookDeliveryFailed { webhook_id, .. } => Some(webhook_id.as_str()),
+            Event::Custom { name, .. } => Some(name.as_str()),
+            _ => value.phone_number(),
+        };
+        Self {
+            kind: EventKind::from(value),
+            subject: subject.map(ToString::to_string),
+        }
+    }
+}
+
+impl Event {
+    /// Get the MQTT topic to publish the event to under a prefix, eg: `home/sms/%2B447700900123/incoming`.
+    #[must_use]
+    pub fn mqtt_topic(&self, prefix: &str) -> String {
+        MqttTopic::from(self).to_topic(prefix)
+    }
+}
+
+/// Percent-encode the characters that can't appear in a topic segment.
+fn encode_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for c in segment.chars() {
+        match c {
+            '%' | '/' | '+' | '#' | '\0' => {
+                let _ = write!(encoded, "%{:02X}", u32::from(c));
+            }
+            c => encoded.push(c),
+        }
+    }
+    encoded
+}
+
+/// Decode a percent-encoded topic segment.
+fn decode_segment(segment: &str) -> Option<String> {
+    let mut decoded = Vec::with_capacity(segment.len());
+    let mut bytes = segment.bytes();
+    while let Some(b) = bytes.next() {
+        if b == b'%' {
+            let hex = [bytes.next()?, bytes.next()?];
+            decoded.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+        } else {
+            decoded.push(b);
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
@@ -0,0 +1,699 @@
+//! Protobuf mirrors of the core types, matching `proto/sms_types.proto`.
+//! Events without a dedicated message are carried as their JSON representation.
+
+use crate::events::{Event as CoreEvent, EventKind};
+
+/// Mirror of `sms::SmsMessage`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SmsMessage {
+    /// Unique identifier for the message.
+    #[prost(int64, optional, tag = "1")]
+    pub message_id: Option<i64>,
+
+    /// The phone number associated with this message.
+    #[prost(string, tag = "2")]
+    pub phone_number: String,
+
+    /// The actual text content of the message.
+    #[prost(string, tag = "3")]
+    pub message_content: String,
+
+    /// Modem assigned reference number (0 - 255).
+    #[prost(uint32, optional, tag = "4")]
+    pub message_reference: Option<u32>,
+
+    /// Whether this message was sent (true) or received (false).
+    #[prost(bool, tag = "5")]
+    pub is_outgoing: bool,
+
+    /// Unix timestamp when the message was created.
+    #[prost(uint32, optional, tag = "6")]
+    pub created_at: Option<u32>,
+
+    /// Unix timestamp when the message was completed/delivered.
+    #[prost(uint32, optional, tag = "7")]
+    pub completed_at: Option<u32>,
+
+    /// Service message center delivery status (0 - 255).
+    #[prost(uint32, optional, tag = "8")]
+    pub status: Option<u32>,
+}
+
+/// Mirror of `Event::OutgoingMessage`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct OutgoingMessage {
+    /// The message being sent.
+    #[prost(message, optional, tag = "1")]
+    pub message: Option<SmsMessage>,
+
+    /// The websocket client id that sent the message.
+    #[prost(uint64, optional, tag = "2")]
+    pub origin_client_id: Option<u64>,
+}
+
+/// Mirror of `Event::DeliveryReport`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DeliveryReport {
+    /// The target `message_id` this delivery report applies to.
+    #[prost(int64, tag = "1")]
+    pub message_id: i64,
+
+    /// The phone number that sent back the delivery report.
+    #[prost(string, tag = "2")]
+    pub phone_number: String,
+
+    /// The modem assigned message reference (0 - 255).
+    #[prost(uint32, tag = "3")]
+    pub reference_id: u32,
+
+    /// The SMS TP-Status (0 - 255).
+    #[prost(uint32, tag = "4")]
+    pub status: u32,
+}
+
+/// Mirror of `modem::ModemStatusUpdateState`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum ModemStatus {
+    /// Modem is starting up.
+    Startup = 0,
+
+    /// Modem is online and operational.
+    Online = 1,
+
+    /// Modem is shutting down.
+    ShuttingDown = 2,
+
+    /// Modem is offline and not operational.
+    Offline = 3,
+}
+
+/// Mirror of `Event::ModemStatusUpdate`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ModemStatusUpdate {
+    /// Previous state from last update.
+    #[prost(enumeration = "ModemStatus", tag = "1")]
+    pub previous: i32,
+
+    /// Current state after update.
+    #[prost(enumeration = "ModemStatus", tag = "2")]
+    pub current: i32,
+}
+
+/// Mirror of `events::Event`, tagged with its `EventKind` name.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Event {
+    /// The serialized `EventKind` name, eg: "incoming".
+    #[prost(string, tag = "1")]
+    pub kind: String,
+
+    /// The event data.
+    #[prost(oneof = "event::Payload", tags = "2, 3, 4, 5, 6, 15")]
+    pub payload: Option<event::Payload>,
+}
+
+/// Nested types for `Event`.
+pub mod event {
+    /// The event data.
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Payload {
+        /// New SMS message received.
+        #[prost(message, tag = "2")]
+        Incoming(super::SmsMessage),
+
+        /// SMS message being sent.
+        #[prost(message, tag = "3")]
+        Outgoing(super::OutgoingMessage),
+
+        /// Delivery report update.
+        #[prost(message, tag = "4")]
+        Delivery(super::DeliveryReport),
+
+        /// Modem hat connection status update.
+        #[prost(message, tag = "5")]
+        ModemStatusUpdate(super::ModemStatusUpdate),
+
+        /// An unsolicited position report from GNSS.
+        #[cfg(feature = "gnss")]
+        #[prost(message, tag = "6")]
+        GnssPositionReport(super::TimedPosition),
+
+        /// Any other event, as its JSON representation.
+        #[prost(string, tag = "15")]
+        Json(String),
+    }
+}
+
+/// Mirror of `gnss::FixStatus`.
+#[cfg(feature = "gnss")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum FixStatus {
+    /// GNSS fix status is unknown.
+    Unknown = 0,
+
+    /// No GNSS fix.
+    NotFix = 1,
+
+    /// 2D GNSS fix.
+    Fix2D = 2,
+
+    /// 3D GNSS fix.
+    Fix3D = 3,
+}
+
+/// Mirror of `gnss::Constellation`.
+#[cfg(feature = "gnss")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum Constellation {
+    /// Unknown constellation.
+    Unknown = 0,
+
+    /// United States GPS.
+    Gps = 1,
+
+    /// Russian GLONASS.
+    Glonass = 2,
+
+    /// European Galileo.
+    Galileo = 3,
+
+    /// Chinese `BeiDou`.
+    BeiDou = 4,
+
+    /// Japanese QZSS.
+    Qzss = 5,
+
+    /// Satellite-based augmentation systems.
+    Sbas = 6,
+}
+
+/// Mirror of `gnss::SatelliteInfo`.
+#[cfg(feature = "gnss")]
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SatelliteInfo {
+    /// The constellation the satellite belongs to.
+    #[prost(enumeration = "Constellation", tag = "1")]
+    pub constellation: i32,
+
+    /// Satellite PRN number.
+    #[prost(uint32, tag = "2")]
+    pub prn: u32,
+
+    /// Signal to noise ratio in dB-Hz.
+    #[prost(uint32, optional, tag = "3")]
+    pub snr: Option<u32>,
+
+    /// Elevation above the horizon in degrees.
+    #[prost(uint32, optional, tag = "4")]
+    pub elevation: Option<u32>,
+
+    /// Azimuth in degrees from true north.
+    #[prost(uint32, optional, tag = "5")]
+    pub azimuth: Option<u32>,
+
+    /// Whether the satellite is used in the current fix.
+    #[prost(bool, tag = "6")]
+    pub used_in_fix: bool,
+}
+
+/// Mirror of `gnss::PositionReport`.
+#[cfg(feature = "gnss")]
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PositionReport {
+    /// GNSS run status.
+    #[prost(bool, tag = "1")]
+    pub run_status: bool,
+
+    /// Fix status.
+    #[prost(bool, tag = "2")]
+    pub fix_status: bool,
+
+    /// UTC date and time in `yyyyMMddhhmmss.sss` format.
+    #[prost(string, tag = "3")]
+    pub utc_time: String,
+
+    /// Latitude in decimal degrees.
+    #[prost(double, optional, tag = "4")]
+    pub latitude: Option<f64>,
+
+    /// Longitude in decimal degrees.
+    #[prost(double, optional, tag = "5")]
+    pub longitude: Option<f64>,
+
+    /// Mean sea level altitude in meters.
+    #[prost(double, optional, tag = "6")]
+    pub msl_altitude: Option<f64>,
+
+    /// Ground speed in meters per second.
+    #[prost(float, optional, tag = "7")]
+    pub ground_speed: Option<f32>,
+
+    /// Ground course in degrees.
+    #[prost(float, optional, tag = "8")]
+    pub ground_course: Option<f32>,
+
+    /// Fix mode.
+    #[prost(enumeration = "FixStatus", tag = "9")]
+    pub fix_mode: i32,
+
+    /// Horizontal dilution of precision.
+    #[prost(float, optional, tag = "10")]
+    pub hdop: Option<f32>,
+
+    /// Position dilution of precision.
+    #[prost(float, optional, tag = "11")]
+    pub pdop: Option<f32>,
+
+    /// Vertical dilution of precision.
+    #[prost(float, optional, tag = "12")]
+    pub vdop: Option<f32>,
+
+    /// GPS satellites in view.
+    #[prost(uint32, optional, tag = "13")]
+    pub gps_in_view: Option<u32>,
+
+    /// GNSS satellites used.
+    #[prost(uint32, optional, tag = "14")]
+    pub gnss_used: Option<u32>,
+
+    /// GLONASS satellites in view.
+    #[prost(uint32, optional, tag = "15")]
+    pub glonass_in_view: Option<u32>,
+
+    /// Per-satellite details.
+    #[prost(message, repeated, tag = "16")]
+    pub satellites: Vec<SatelliteInfo>,
+}
+
+/// Mirror of `gnss::CoarseLocation`.
+#[cfg(feature = "gnss")]
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CoarseLocation {
+    /// Mobile Country Code.
+    #[prost(uint32, tag = "1")]
+    pub mcc: u32,
+
+    /// Mobile Network Code.
+    #[prost(uint32, tag = "2")]
+    pub mnc: u32,
+
+    /// Location Area Code.
+    #[prost(uint32, tag = "3")]
+    pub lac: u32,
+
+    /// Serving cell identifier.
+    #[prost(uint32, tag = "4")]
+    pub cell_id: u32,
+
+    /// Resolved latitude in decimal degrees.
+    #[prost(double, optional, tag = "5")]
+    pub latitude: Option<f64>,
+
+    /// Resolved longitude in decimal degrees.
+    #[prost(double, optional, tag = "6")]
+    pub longitude: Option<f64>,
+
+    /// Accuracy radius in meters.
+    #[prost(double, optional, tag = "7")]
+    pub accuracy: Option<f64>,
+}
+
+/// Mirror of `gnss::TimedPosition`.
+#[cfg(feature = "gnss")]
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TimedPosition {
+    /// The position report.
+    #[prost(message, optional, tag = "1")]
+    pub report: Option<PositionReport>,
+
+    /// Unix timestamp in milliseconds when the report was received.
+    #[prost(uint64, tag = "2")]
+    pub received_at: u64,
+
+    /// A cell based location, included when there is no satellite fix.
+    #[prost(message, optional, tag = "3")]
+    pub coarse: Option<CoarseLocation>,
+}
+
+/// Narrow a protobuf integer into the core type, naming the field on failure.
+fn narrow<T: TryFrom<u32>>(value: u32, field: &str) -> Result<T, String> {
+    T::try_from(value).map_err(|_| format!("Field {field} is out of range: {value}"))
+}
+
+/// Narrow an optional protobuf integer into the core type.
+fn narrow_opt<T: TryFrom<u32>>(value: Option<u32>, field: &str) -> Result<Option<T>, String> {
+    value.map(|value| narrow(value, field)).transpose()
+}
+
+impl From<&crate::sms::SmsMessage> for SmsMessage {
+    fn from(value: &crate::sms::SmsMessage) -> Self {
+        Self {
+            message_id: value.message_id,
+            phone_number: value.phone_number.to_string(),
+            message_content: value.message_content.to_string(),
+            message_reference: value.message_reference.map(u32::from),
+            is_outgoing: value.is_outgoing,
+            created_at: value.created_at,
+            completed_at: value.completed_at,
+            status: value.status.map(u32::from),
+        }
+    }
+}
+impl TryFrom<SmsMessage> for crate::sms::SmsMessage {
+    type Error = String;
+
+    fn try_from(value: SmsMessage) -> Result<Self, Self::Error> {
+        Ok(Self {
+            message_id: value.message_id,
+            phone_number: value.phone_number.into(),
+            message_content: value.message_content.into(),
+            message_reference: narrow_opt(value.message_reference, "message_reference")?,
+            is_outgoing: value.is_outgoing,
+            created_at: value.created_at,
+            completed_at: value.completed_at,
+            status: narrow_opt(value.status, "status")?,
+        })
+    }
+}
+
+impl TryFrom<&crate::modem::ModemStatusUpdateState> for ModemStatus {
+    type Error = String;
+
+    /// Convert a modem state, failing for `Other` states that have no mirror.
+    fn try_from(value: &crate::modem::ModemStatusUpdateState) -> Result<Self, Self::Error> {
+        use crate::modem::ModemStatusUpdateState;
+
+        match value {
+            ModemStatusUpdateState::Startup => Ok(ModemStatus::Startup),
+            ModemStatusUpdateState::Online => Ok(ModemStatus::Online),
+            ModemStatusUpdateState::ShuttingDown => Ok(ModemStatus::ShuttingDown),
+            ModemStatusUpdateState::Offline => Ok(ModemStatus::Offline),
+            ModemStatusUpdateState::Other(name) => {
+                Err(format!("Unmirrored modem status: '{name}'"))
+            }
+        }
+    }
+}
+impl From<ModemStatus> for crate::modem::ModemStatusUpdateState {
+    fn from(value: ModemStatus) -> Self {
+        match value {
+            ModemStatus::Startup => Self::Startup,
+            ModemStatus::Online => Self::Online,
+            ModemStatus::ShuttingDown => Self::ShuttingDown,
+            ModemStatus::Offline => Self::Offline,
+        }
+    }
+}
+
+/// Get a modem status from its protobuf enum value.
+fn modem_status(value: i32) -> Result<crate::modem::ModemStatusUpdateState, String> {
+    ModemStatus::try_from(value)
+        .map(Into::into)
+        .map_err(|_| format!("Invalid modem status: {value}"))
+}
+
+#[cfg(feature = "gnss")]
+impl From<&crate::gnss::FixStatus> for FixStatus {
+    fn from(value: &crate::gnss::FixStatus) -> Self {
+        match value {
+            crate::gnss::FixStatus::Unknown => FixStatus::Unknown,
+            crate::gnss::FixStatus::NotFix => FixStatus::NotFix,
+            crate::gnss::FixStatus::Fix2D => FixStatus::Fix2D,
+            crate::gnss::FixStatus::Fix3D => FixStatus::Fix3D,
+        }
+    }
+}
+#[cfg(feature = "gnss")]
+impl From<FixStatus> for crate::gnss::FixStatus {
+    fn from(value: FixStatus) -> Self {
+        match value {
+            FixStatus::Unknown => Self::Unknown,
+            FixStatus::NotFix => Self::NotFix,
+            FixStatus::Fix2D => Self::Fix2D,
+            FixStatus::Fix3D => Self::Fix3D,
+        }
+    }
+}
+
+#[cfg(feature = "gnss")]
+impl From<crate::gnss::Constellation> for Constellation {
+    fn from(value: crate::gnss::Constellation) -> Self {
+        match value {
+            crate::gnss::Constellation::Gps => Constellation::Gps,
+            crate::gnss::Constellation::Glonass => Constellation::Glonass,
+            crate::gnss::Constellation::Galileo => Constellation::Galileo,
+            crate::gnss::Constellation::BeiDou => Constellation::BeiDou,
+            crate::gnss::Constellation::Qzss => Constellation::Qzss,
+            crate::gnss::Constellation::Sbas => Constellation::Sbas,
+            crate::gnss::Constellation::Unknown => Constellation::Unknown,
+        }
+    }
+}
+#[cfg(feature = "gnss")]
+impl From<Constellation> for crate::gnss::Constellation {
+    fn from(value: Constellation) -> Self {
+        match value {
+            Constellation::Gps => Self::Gps,
+            Constellation::Glonass => Self::Glonass,
+            Constellation::Galileo => Self::Galileo,
+            Constellation::BeiDou => Self::BeiDou,
+            Constellation::Qzss => Self::Qzss,
+            Constellation::Sbas => Self::Sbas,
+            Constellation::Unknown => Self::Unknown,
+        }
+    }
+}
+
+#[cfg(feature = "gnss")]
+impl From<&crate::gnss::SatelliteInfo> for SatelliteInfo {
+    fn from(value: &crate::gnss::SatelliteInfo) -> Self {
+        Self {
+            constellation: Constellation::from(value.constellation) as i32,
+            prn: u32::from(value.prn),
+            snr: value.snr.map(u32::from),
+            elevation: value.elevation.map(u32::from),
+            azimuth: value.azimuth.map(u32::from),
+            used_in_fix: value.used_in_fix,
+        }
+    }
+}
+#[cfg(feature = "gnss")]
+impl TryFrom<SatelliteInfo> for crate::gnss::SatelliteInfo {
+    type Error = String;
+
+    fn try_from(value: SatelliteInfo) -> Result<Self, Self::Error> {
+        Ok(Self {
+            constellation: Constellation::try_from(value.constellation)
+                .map_err(|_| format!("Invalid constellation: {}", value.constellation))?
+                .into(),
+            prn: narrow(value.prn, "prn")?,
+            snr: narrow_opt(value.snr, "snr")?,
+            elevation: narrow_opt(value.elevation, "elevation")?,
+            azimuth: narrow_opt(value.azimuth, "azimuth")?,
+            used_in_fix: value.used_in_fix,
+        })
+    }
+}
+
+#[cfg(feature = "gnss")]
+impl From<&crate::gnss::PositionReport> for PositionReport {
+    fn from(value: &crate::gnss::PositionReport) -> Self {
+        Self {
+            run_status: value.run_status,
+            fix_status: value.fix_status,
+            utc_time: value.utc_time.clone(),
+            latitude: value.latitude,
+            longitude: value.longitude,
+            msl_altitude: value.msl_altitude,
+            ground_speed: value.ground_speed,
+            ground_course: value.ground_course,
+            fix_mode: FixStatus::from(&value.fix_mode) as i32,
+            hdop: value.hdop,
+            pdop: value.pdop,
+            vdop: value.vdop,
+            gps_in_view: value.gps_in_view.map(u32::from),
+            gnss_used: value.gnss_used.map(u32::from),
+            glonass_in_view: value.glonass_in_view.map(u32::from),
+            satellites: value.satellites.iter().map(SatelliteInfo::from).collect(),
+        }
+    }
+}
+#[cfg(feature = "gnss")]
+impl TryFrom<PositionReport> for crate::gnss::PositionReport {
+    type Error = String;
+
+    fn try_from(value: PositionReport) -> Result<Self, Self::Error> {
+        Ok(Self {
+            run_status: value.run_status,
+            fix_status: value.fix_status,
+            utc_time: value.utc_time,
+            latitude: value.latitude,
+            longitude: value.longitude,
+            msl_altitude: value.msl_altitude,
+            ground_speed: value.ground_speed,
+            ground_course: value.ground_course,
+            fix_mode: FixStatus::try_from(value.fix_mode)
+                .map_err(|_| format!("Invalid fix mode: {}", value.fix_mode))?
+                .into(),
+            hdop: value.hdop,
+            pdop: value.pdop,
+            vdop: value.vdop,
+            gps_in_view: narrow_opt(value.gps_in_view, "gps_in_view")?,
+            gnss_used: narrow_opt(value.gnss_used, "gnss_used")?,
+            glonass_in_view: narrow_opt(value.glonass_in_view, "glonass_in_view")?,
+            satellites: value
+                .satellites
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[cfg(feature = "gnss")]
+impl From<&crate::gnss::CoarseLocation> for CoarseLocation {
+    fn from(value: &crate::gnss::CoarseLocation) -> Self {
+        Self {
+            mcc: u32::from(value.mcc),
+            mnc: u32::from(value.mnc),
+            lac: value.lac,
+            cell_id: value.cell_id,
+            latitude: value.location.map(|point| point.latitude),
+            longitude: value.location.map(|point| point.longitude),
+            accuracy: value.accuracy,
+        }
+    }
+}
+#[cfg(feature = "gnss")]
+impl TryFrom<CoarseLocation> for crate::gnss::CoarseLocation {
+    type Error = String;
+
+    fn try_from(value: CoarseLocation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            mcc: narrow(value.mcc, "mcc")?,
+            mnc: narrow(value.mnc, "mnc")?,
+            lac: value.lac,
+            cell_id: value.cell_id,
+            location: value
+                .latitude
+                .zip(value.longitude)
+                .map(|(latitude, longitude)| crate::gnss::GeoPoint::new(latitude, longitude)),
+            accuracy: value.accuracy,
+        })
+    }
+}
+
+#[cfg(feature = "gnss")]
+impl From<&crate::gnss::TimedPosition> for TimedPosition {
+    fn from(value: &crate::gnss::TimedPosition) -> Self {
+        Self {
+            report: Some(PositionReport::from(&value.report)),
+            received_at: value.received_at,
+            coarse: value.coarse.as_ref().map(CoarseLocation::from),
+        }
+    }
+}
+#[cfg(feature = "gnss")]
+impl TryFrom<TimedPosition> for crate::gnss::TimedPosition {
+    type Error = String;
+
+    fn try_from(value: TimedPosition) -> Result<Self, Self::Error> {
+        Ok(Self {
+            report: value.report.ok_or("Missing position report")?.try_into()?,
+            received_at: value.received_at,
+            coarse: value.coarse.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl From<&CoreEvent> for Event {
+    fn from(value: &CoreEvent) -> Self {
+        let payload = match value {
+            CoreEvent::IncomingMessage(message) => event::Payload::Incoming(message.into()),
+            CoreEvent::OutgoingMessage {
+                message,
+                origin_client_id,
+            } => event::Payload::Outgoing(OutgoingMessage {
+                message: Some(message.into()),
+                origin_client_id: *origin_client_id,
+            }),
+            CoreEvent::DeliveryReport { message_id, report } => {
+                event::Payload::Delivery(DeliveryReport {
+                    message_id: *message_id,
+                    phone_number: report.phone_number.clone(),
+                    reference_id: u32::from(report.reference_id),
+                    status: u32::from(report.status),
+                })
+            }
+            CoreEvent::ModemStatusUpdate { previous, current } => {
+                match (
+                    ModemStatus::try_from(previous),
+                    ModemStatus::try_from(current),
+                ) {
+                    (Ok(previous), Ok(current)) => {
+                        event::Payload::ModemStatusUpdate(ModemStatusUpdate {
+                            previous: previous as i32,
+                            current: current as i32,
+                        })
+                    }
+                    _ => json_payload(value),
+                }
+            }
+
+            #[cfg(feature = "gnss")]
+            CoreEvent::GnssPositionReport(position) => {
+                event::Payload::GnssPositionReport(position.into())
+            }
+
+            other => json_payload(other),
+        };
+        Self {
+            kind: EventKind::from(value).to_string(),
+            payload: Some(payload),
+        }
+    }
+}
+
+/// Get the JSON payload for an event without a mirror.
+fn json_payload(event: &CoreEvent) -> event::Payload {
+    // Serializing an event into a JSON string cannot fail.
+    event::Payload::Json(serde_json::to_string(event).unwrap_or_default())
+}
+
+impl TryFrom<Event> for CoreEvent {
+    type Error = String;
+
+    fn try_from(value: Event) -> Result<Self, Self::Error> {
+        Ok(match value.payload.ok_or("Missing event payload")? {
+            event::Payload::Incoming(message) => CoreEvent::IncomingMessage(message.try_into()?),
+            event::Payload::Outgoing(outgoing) => CoreEvent::OutgoingMessage {
+                message: outgoing
+                    .message
+                    .ok_or("Missing outgoing message")?
+                    .try_into()?,
+                origin_client_id: outgoing.origin_client_id,
+            },
+            event::Payload::Delivery(delivery) => CoreEvent::DeliveryReport {
+                message_id: delivery.message_id,
+                report: crate::sms::SmsPartialDeliveryReport {
+                    phone_number: delivery.phone_number,
+                    reference_id: narrow(delivery.reference_id, "reference_id")?,
+                    status: narrow(delivery.status, "status")?,
+                },
+            },
+            event::Payload::ModemStatusUpdate(update) => CoreEvent::ModemStatusUpdate {
+                previous: modem_status(update.previous)?,
+                current: modem_status(update.current)?,
+            },
+
+            #[cfg(feature = "gnss")]
+            event::Payload::GnssPositionReport(position) => {
+                CoreEvent::GnssPositionReport(position.try_into()?)
+            }
+
+            event::Payload::Json(json) => serde_json::from_str(&json).map_err(|e| e.to_string())?,
+        })
+    }
+}
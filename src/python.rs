@@ -0,0 +1,413 @@
+//! Python classes wrapping the core types, for scripts that construct or parse payloads.
+//! Use `register` to add the classes to an extension module.
+
+use crate::events::{Event, EventKind};
+use crate::sms::{SmsMessage, SmsOutgoingMessage};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Convert a JSON error into a Python `ValueError`.
+fn value_error(error: &serde_json::Error) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// Python wrapper for `SmsMessage`.
+#[pyclass(name = "SmsMessage", module = "sms_types", eq, from_py_object)]
+#[derive(PartialEq, Debug, Clone)]
+pub struct PySmsMessage(pub SmsMessage);
+
+#[pymethods]
+impl PySmsMessage {
+    #[new]
+    #[pyo3(signature = (phone_number, message_content, is_outgoing, message_id=None, message_reference=None, created_at=None, completed_at=None, status=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        phone_number: String,
+        message_content: String,
+        is_outgoing: bool,
+        message_id: Option<i64>,
+        message_reference: Option<u8>,
+        created_at: Option<u32>,
+        completed_at: Option<u32>,
+        status: Option<u8>,
+    ) -> Self {
+        Self(SmsMessage {
+            message_id,
+            phone_number: phone_number.into(),
+            message_content: message_content.into(),
+            message_reference,
+            is_outgoing,
+            created_at,
+            completed_at,
+            status,
+        })
+    }
+
+    #[getter]
+    fn message_id(&self) -> Option<i64> {
+        self.0.message_id
+    }
+
+    #[getter]
+    fn phone_number(&self) -> &str {
+        &self.0.phone_number
+    }
+
+    #[getter]
+    fn message_content(&self) -> &str {
+        &self.0.message_content
+    }
+
+    #[getter]
+    fn message_reference(&self) -> Option<u8> {
+        self.0.message_reference
+    }
+
+    #[getter]
+    fn is_outgoing(&self) -> bool {
+        self.0.is_outgoing
+    }
+
+    #[getter]
+    fn created_at(&self) -> Option<u32> {
+        self.0.created_at
+    }
+
+    #[getter]
+    fn completed_at(&self) -> Option<u32> {
+        self.0.completed_at
+    }
+
+    #[getter]
+    fn status(&self) -> Option<u8> {
+        self.0.status
+    }
+
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.0).map_err(|e| value_error(&e))
+    }
+
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        serde_json::from_str(json)
+            .map(Self)
+            .map_err(|e| value_error(&e))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+impl From<SmsMessage> for PySmsMessage {
+    fn from(value: SmsMessage) -> Self {
+        Self(value)
+    }
+}
+impl From<PySmsMessage> for SmsMessage {
+    fn from(value: PySmsMessage) -> Self {
+        value.0
+    }
+}
+
+/// Python wrapper for `SmsOutgoingMessage`.
+#[pyclass(name = "SmsOutgoingMessage", module = "sms_types", eq, from_py_object)]
+#[derive(PartialEq, Debug, Clone)]
+pub struct PySmsOutgoingMessage(pub SmsOutgoingMessage);
+
+#[pymethods]
+impl PySmsOutgoingMessage {
+    #[new]
+    #[pyo3(signature = (to, content, validity_period=None, flash=None, timeout=None))]
+    fn new(
+        to: String,
+        content: String,
+        validity_period: Option<u8>,
+        flash: Option<bool>,
+        timeout: Option<u32>,
+    ) -> Self {
+        Self(SmsOutgoingMessage {
+            to,
+            content,
+            validity_period,
+            flash,
+            timeout,
+        })
+    }
+
+    #[getter]
+    fn to(&self) -> &str {
+        &self.0.to
+    }
+
+    #[getter]
+    fn content(&self) -> &str {
+        &self.0.content
+    }
+
+    #[getter]
+    fn validity_period(&self) -> Option<u8> {
+        self.0.validity_period
+    }
+
+    #[getter]
+    fn flash(&self) -> Option<bool> {
+        self.0.flash
+    }
+
+    #[getter]
+    fn timeout(&self) -> Option<u32> {
+        self.0.timeout
+    }
+
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.0).map_err(|e| value_error(&e))
+    }
+
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        serde_json::from_str(json)
+            .map(Self)
+            .map_err(|e| value_error(&e))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+impl From<SmsOutgoingMessage> for PySmsOutgoingMessage {
+    fn from(value: SmsOutgoingMessage) -> Self {
+        Self(value)
+    }
+}
+impl From<PySmsOutgoingMessage> for SmsOutgoingMessage {
+    fn from(value: PySmsOutgoingMessage) -> Self {
+        value.0
+    }
+}
+
+/// Python wrapper for `gnss::PositionReport`.
+#[cfg(feature = "gnss")]
+#[pyclass(name = "PositionReport", module = "sms_types", eq, from_py_object)]
+#[derive(PartialEq, Debug, Clone)]
+pub struct PyPositionReport(pub crate::gnss::PositionReport);
+
+#[cfg(feature = "gnss")]
+#[pymethods]
+impl PyPositionReport {
+    #[new]
+    #[pyo3(signature = (run_status, fix_status, utc_time, fix_mode="Unknown", latitude=None, longitude=None, msl_altitude=None, ground_speed=None, ground_course=None, hdop=None, pdop=None, vdop=None, gps_in_view=None, gnss_used=None, glonass_in_view=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        run_status: bool,
+        fix_status: bool,
+        utc_time: String,
+        fix_mode: &str,
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        msl_altitude: Option<f64>,
+        ground_speed: Option<f32>,
+        ground_course: Option<f32>,
+        hdop: Option<f32>,
+        pdop: Option<f32>,
+        vdop: Option<f32>,
+        gps_in_view: Option<u8>,
+        gnss_used: Option<u8>,
+        glonass_in_view: Option<u8>,
+    ) -> PyResult<Self> {
+        Ok(Self(crate::gnss::PositionReport {
+            run_status,
+            fix_status,
+            utc_time,
+            latitude,
+            longitude,
+            msl_altitude,
+            ground_speed,
+            ground_course,
+            fix_mode: fix_mode.parse().map_err(PyValueError::new_err)?,
+            hdop,
+            pdop,
+            vdop,
+            gps_in_view,
+            gnss_used,
+            glonass_in_view,
+            satellites: Vec::new(),
+        }))
+    }
+
+    #[getter]
+    fn run_status(&self) -> bool {
+        self.0.run_status
+    }
+
+    #[getter]
+    fn fix_status(&self) -> bool {
+        self.0.fix_status
+    }
+
+    #[getter]
+    fn utc_time(&self) -> &str {
+        &self.0.utc_time
+    }
+
+    #[getter]
+    fn latitude(&self) -> Option<f64> {
+        self.0.latitude
+    }
+
+    #[getter]
+    fn longitude(&self) -> Option<f64> {
+        self.0.longitude
+    }
+
+    #[getter]
+    fn msl_altitude(&self) -> Option<f64> {
+        self.0.msl_altitude
+    }
+
+    #[getter]
+    fn ground_speed(&self) -> Option<f32> {
+        self.0.ground_speed
+    }
+
+    #[getter]
+    fn ground_course(&self) -> Option<f32> {
+        self.0.ground_course
+    }
+
+    #[getter]
+    fn fix_mode(&self) -> String {
+        self.0.fix_mode.to_string()
+    }
+
+    #[getter]
+    fn hdop(&self) -> Option<f32> {
+        self.0.hdop
+    }
+
+    #[getter]
+    fn pdop(&self) -> Option<f32> {
+        self.0.pdop
+    }
+
+    #[getter]
+    fn vdop(&self) -> Option<f32> {
+        self.0.vdop
+    }
+
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.0).map_err(|e| value_error(&e))
+    }
+
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        serde_json::from_str(json)
+            .map(Self)
+            .map_err(|e| value_error(&e))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+#[cfg(feature = "gnss")]
+impl From<crate::gnss::PositionReport> for PyPositionReport {
+    fn from(value: crate::gnss::PositionReport) -> Self {
+        Self(value)
+    }
+}
+#[cfg(feature = "gnss")]
+impl From<PyPositionReport> for crate::gnss::PositionReport {
+    fn from(value: PyPositionReport) -> Self {
+        value.0
+    }
+}
+
+/// Python wrapper for `Event`.
+#[pyclass(name = "Event", module = "sms_types", eq, from_py_object)]
+#[derive(PartialEq, Debug, Clone)]
+pub struct PyEvent(pub Event);
+
+#[pymethods]
+impl PyEvent {
+    /// Create an incoming message event.
+    #[staticmethod]
+    fn incoming(message: PySmsMessage) -> Self {
+        Self(Event::IncomingMessage(message.0))
+    }
+
+    /// Create an outgoing message event.
+    #[staticmethod]
+    #[pyo3(signature = (message, origin_client_id=None))]
+    fn outgoing(message: PySmsMessage, origin_client_id: Option<u64>) -> Self {
+        Self(Event::OutgoingMessage {
+            message: message.0,
+            origin_client_id,
+        })
+    }
+
+    /// The serialized event kind name, eg: "incoming".
+    #[getter]
+    fn kind(&self) -> &'static str {
+        EventKind::from(&self.0).as_str()
+    }
+
+    /// The message of an incoming or outgoing message event.
+    #[getter]
+    fn message(&self) -> Option<PySmsMessage> {
+        match &self.0 {
+            Event::IncomingMessage(message) | Event::OutgoingMessage { message, .. } => {
+                Some(PySmsMessage(message.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// The position report of a GNSS position report event.
+    #[cfg(feature = "gnss")]
+    #[getter]
+    fn position(&self) -> Option<PyPositionReport> {
+        match &self.0 {
+            Event::GnssPositionReport(position) => Some(PyPositionReport(position.report.clone())),
+            _ => None,
+        }
+    }
+
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.0).map_err(|e| value_error(&e))
+    }
+
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        serde_json::from_str(json)
+            .map(Self)
+            .map_err(|e| value_error(&e))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+impl From<Event> for PyEvent {
+    fn from(value: Event) -> Self {
+        Self(value)
+    }
+}
+impl From<PyEvent> for Event {
+    fn from(value: PyEvent) -> Self {
+        value.0
+    }
+}
+
+/// Add the classes to a Python module.
+///
+/// # Errors
+/// Returns an error if a class cannot be added to the module.
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PySmsMessage>()?;
+    module.add_class::<PySmsOutgoingMessage>()?;
+    module.add_class::<PyEvent>()?;
+
+    #[cfg(feature = "gnss")]
+    module.add_class::<PyPositionReport>()?;
+    Ok(())
+}
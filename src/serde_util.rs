@@ -0,0 +1,200 @@
+//! Serde adapters for binary fields, so every transport encodes them identically.
+//!
+//! eg: `#[serde(with = "crate::serde_util::hex")] pub pdu: Vec<u8>`
+
+/// Encode bytes as a lowercase hex string, eg: raw PDUs as "07914477".
+/// Decoding accepts either case.
+pub mod hex {
+    use alloc::{string::String, vec::Vec};
+    use serde::{Deserialize, Deserializer, Serializer, de};
+
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    /// Encode bytes as a lowercase hex string.
+    #[must_use]
+    pub fn encode(bytes: &[u8]) -> String {
+        let mut encoded = String::with_capacity(bytes.len() * 2);
+        for b in bytes {
+            encoded.push(char::from(DIGITS[usize::from(b >> 4)]));
+            encoded.push(char::from(DIGITS[usize::from(b & 0x0F)]));
+        }
+        encoded
+    }
+
+    /// Decode a hex string, returning None if it has an odd length or a non-hex character.
+    #[must_use]
+    pub fn decode(value: &str) -> Option<Vec<u8>> {
+        if !value.len().is_multiple_of(2) {
+            return None;
+        }
+        value
+            .as_bytes()
+            .chunks_exact(2)
+            .map(|pair| {
+                let high = char::from(pair[0]).to_digit(16)?;
+                let low = char::from(pair[1]).to_digit(16)?;
+                u8::try_from(high << 4 | low).ok()
+            })
+            .collect()
+    }
+
+    /// Serialize bytes as a hex string.
+    ///
+    /// # Errors
+    /// Returns the serializer's error if the string can't be written.
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encode(value))
+    }
+
+    /// Deserialize bytes from a hex string.
+    ///
+    /// # Errors
+    /// Returns an error if the value isn't a string of valid hex.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        decode(&value).ok_or_else(|| de::Error::custom("invalid hex string"))
+    }
+}
+
+/// Encode bytes as standard padded base64, eg: binary message payloads.
+pub mod base64 {
+    use alloc::{string::String, vec::Vec};
+    use serde::{Deserialize, Deserializer, Serializer, de};
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    /// Encode bytes as padded base64.
+    #[must_use]
+    pub fn encode(bytes: &[u8]) -> String {
+        let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        for chunk in bytes.chunks(3) {
+            let group = chunk
+                .iter()
+                .enumerate()
+                .fold(0u32, |group, (i, b)| group | u32::from(*b) << (16 - i * 8));
+
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    let index = (group >> (18 - i * 6)) & 0x3F;
+                    encoded.push(char::from(ALPHABET[index as usize]));
+                } else {
+                    encoded.push('=');
+                }
+            }
+        }
+        encoded
+    }
+
+    /// Decode padded base64, returning None if it isn't valid.
+    #[must_use]
+    pub fn decode(value: &str) -> Option<Vec<u8>> {
+        let value = value.as_bytes();
+        if !value.len().is_multiple_of(4) {
+            return None;
+        }
+
+        let mut decoded = Vec::with_capacity(value.len() / 4 * 3);
+        let chunks = value.len() / 4;
+        for (n, chunk) in value.chunks_exact(4).enumerate() {
+            let padding = chunk.iter().rev().take_while(|c| **c == b'=').count();
+            if padding > 2 || (padding > 0 && n + 1 != chunks) {
+                return None;
+            }
+
+            let mut group = 0u32;
+            for (i, c) in chunk[..4 - padding].iter().enumerate() {
+                let index = ALPHABET.iter().position(|a| a == c)?;
+                group |= u32::try_from(index).ok()? << (18 - i * 6);
+            }
+            let bytes = group.to_be_bytes();
+            decoded.extend_from_slice(&bytes[1..4 - padding]);
+        }
+        Some(decoded)
+    }
+
+    /// Serialize bytes as a base64 string.
+    ///
+    /// # Errors
+    /// Returns the serializer's error if the string can't be written.
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encode(value))
+    }
+
+    /// Deserialize bytes from a base64 string.
+    ///
+    /// # Errors
+    /// Returns an error if the value isn't a string of valid base64.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        decode(&value).ok_or_else(|| de::Error::custom("invalid base64 string"))
+    }
+}
+
+/// Encode GSM 03.40 address semi-octets as the digits they represent,
+/// eg: `[0x44, 0x77, 0x00, 0x09, 0x21, 0xF3]` as "44770090123".
+///
+/// Each byte holds two digits with the low nibble first, and an odd number
+/// of digits is padded with a trailing 0xF nibble. Nibbles 0xA to 0xE are
+/// the extended digits `*`, `#`, `a`, `b` and `c`.
+pub mod semi_octet {
+    use alloc::{string::String, vec::Vec};
+    use serde::{Deserialize, Deserializer, Serializer, de};
+
+    const DIGITS: &[u8; 15] = b"0123456789*#abc";
+
+    /// Encode semi-octets as a digit string, returning None if a filler nibble isn't last.
+    #[must_use]
+    pub fn encode(bytes: &[u8]) -> Option<String> {
+        let mut encoded = String::with_capacity(bytes.len() * 2);
+        for (i, b) in bytes.iter().enumerate() {
+            encoded.push(char::from(*DIGITS.get(usize::from(b & 0x0F))?));
+            match b >> 4 {
+                0x0F if i + 1 == bytes.len() => {}
+                high => encoded.push(char::from(*DIGITS.get(usize::from(high))?)),
+            }
+        }
+        Some(encoded)
+    }
+
+    /// Decode a digit string into semi-octets, returning None if it has an unsupported character.
+    #[must_use]
+    pub fn decode(value: &str) -> Option<Vec<u8>> {
+        value
+            .as_bytes()
+            .chunks(2)
+            .map(|pair| {
+                let nibble = |c: u8| {
+                    DIGITS
+                        .iter()
+                        .position(|d| *d == c)
+                        .and_then(|n| u8::try_from(n).ok())
+                };
+                let low = nibble(pair[0])?;
+                let high = match pair.get(1) {
+                    Some(c) => nibble(*c)?,
+                    None => 0x0F,
+                };
+                Some(high << 4 | low)
+            })
+            .collect()
+    }
+
+    /// Serialize semi-octets as a digit string.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes aren't valid semi-octets.
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        let encoded =
+            encode(value).ok_or_else(|| serde::ser::Error::custom("invalid semi-octet address"))?;
+        serializer.serialize_str(&encoded)
+    }
+
+    /// Deserialize semi-octets from a digit string.
+    ///
+    /// # Errors
+    /// Returns an error if the value isn't a string of address digits.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        decode(&value).ok_or_else(|| de::Error::custom("invalid semi-octet address"))
+    }
+}
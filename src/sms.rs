@@ -1,18 +1,34 @@
 //! Generic types that apply to both HTTP and Websocket interfaces.
 
+use crate::timestamp::Timestamp;
+use alloc::{borrow::Cow, format, string::String, sync::Arc, vec::Vec};
 use serde::{Deserialize, Serialize};
 
 /// Represents a stored SMS message from the database.
+/// The phone number and content are shared, so clones fanned out to many clients are cheap.
+/// With the `zeroize` feature, the last owner of the content scrubs it when zeroized.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub struct SmsMessage {
     /// Unique identifier for the message.
     pub message_id: Option<i64>,
 
     /// The phone number associated with this message.
-    pub phone_number: String,
+    #[cfg_attr(feature = "sqlx", sqlx(try_from = "String"))]
+    pub phone_number: Arc<str>,
 
     /// The actual text content of the message.
-    pub message_content: String,
+    #[cfg_attr(feature = "sqlx", sqlx(try_from = "String"))]
+    pub message_content: Arc<str>,
 
     /// Optional reference number for message tracking.
     /// This is assigned by the modem and is only present for outgoing messages.
@@ -40,16 +56,160 @@ impl SmsMessage {
         }
     }
 
-    /// Get the message created_at time as SystemTime.
+    /// Get the message `created_at` time as `SystemTime`.
+    #[cfg(feature = "std")]
     #[must_use]
     pub fn created_at(&self) -> Option<std::time::SystemTime> {
+        self.created_timestamp().map(Into::into)
+    }
+
+    /// Get when the message was created.
+    #[must_use]
+    pub fn created_timestamp(&self) -> Option<Timestamp> {
         self.created_at
-            .map(|ts| std::time::UNIX_EPOCH + std::time::Duration::from_secs(u64::from(ts)))
+            .map(|seconds| Timestamp::from_unix_seconds(u64::from(seconds)))
+    }
+
+    /// Get when the message was completed/delivered.
+    #[must_use]
+    pub fn completed_timestamp(&self) -> Option<Timestamp> {
+        self.completed_at
+            .map(|seconds| Timestamp::from_unix_seconds(u64::from(seconds)))
+    }
+}
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for SmsMessage {
+    /// Scrub the phone number and content in place when this message is their only owner.
+    /// Storage shared with other clones is only released, as they may still be reading it.
+    fn zeroize(&mut self) {
+        fn zeroize_shared(value: &mut Arc<str>) {
+            if let Some(value) = Arc::get_mut(value) {
+                zeroize::Zeroize::zeroize(value);
+            }
+            *value = Arc::from("");
+        }
+
+        self.message_id.zeroize();
+        zeroize_shared(&mut self.phone_number);
+        zeroize_shared(&mut self.message_content);
+        self.message_reference.zeroize();
+        self.is_outgoing.zeroize();
+        self.created_at.zeroize();
+        self.completed_at.zeroize();
+        self.status.zeroize();
+    }
+}
+#[cfg(feature = "cbor")]
+impl SmsMessage {
+    /// Encode the message as deterministic CBOR.
+    ///
+    /// # Errors
+    /// Returns an error if the message cannot be serialized.
+    pub fn to_cbor(&self) -> Result<Vec<u8>, crate::cbor::EncodeError> {
+        crate::cbor::to_vec(self)
+    }
+
+    /// Decode a message from CBOR.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid encoded message.
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, crate::cbor::DecodeError> {
+        crate::cbor::from_slice(bytes)
+    }
+}
+
+#[cfg(feature = "avro")]
+impl SmsMessage {
+    /// Encode the message as an Avro datum, using `avro::sms_message_schema`.
+    ///
+    /// # Errors
+    /// Returns an error if the message cannot be serialized.
+    pub fn to_avro(&self) -> Result<Vec<u8>, crate::avro::AvroError> {
+        crate::avro::encode_sms_message(self)
+    }
+
+    /// Decode a message from an Avro datum.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid encoded message.
+    pub fn from_avro(bytes: &[u8]) -> Result<Self, crate::avro::AvroError> {
+        crate::avro::decode_sms_message(bytes)
+    }
+}
+
+/// A message borrowed from its serialized form, eg: for filtering or routing webhook
+/// payloads without allocating. Strings are only copied if they contain escapes.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct SmsMessageRef<'a> {
+    /// Unique identifier for the message.
+    pub message_id: Option<i64>,
+
+    /// The phone number associated with this message.
+    #[serde(borrow)]
+    pub phone_number: Cow<'a, str>,
+
+    /// The actual text content of the message.
+    #[serde(borrow)]
+    pub message_content: Cow<'a, str>,
+
+    /// Optional reference number for message tracking.
+    pub message_reference: Option<u8>,
+
+    /// Whether this message was sent (true) or received (false).
+    pub is_outgoing: bool,
+
+    /// Unix timestamp when the message was created.
+    pub created_at: Option<u32>,
+
+    /// Optional Unix timestamp when the message was completed/delivered.
+    pub completed_at: Option<u32>,
+
+    /// Service message center delivery status.
+    pub status: Option<u8>,
+}
+impl<'a> From<&'a SmsMessage> for SmsMessageRef<'a> {
+    fn from(value: &'a SmsMessage) -> Self {
+        Self {
+            message_id: value.message_id,
+            phone_number: Cow::Borrowed(&value.phone_number),
+            message_content: Cow::Borrowed(&value.message_content),
+            message_reference: value.message_reference,
+            is_outgoing: value.is_outgoing,
+            created_at: value.created_at,
+            completed_at: value.completed_at,
+            status: value.status,
+        }
+    }
+}
+impl From<SmsMessageRef<'_>> for SmsMessage {
+    fn from(value: SmsMessageRef<'_>) -> Self {
+        Self {
+            message_id: value.message_id,
+            phone_number: value.phone_number.into(),
+            message_content: value.message_content.into(),
+            message_reference: value.message_reference,
+            is_outgoing: value.is_outgoing,
+            created_at: value.created_at,
+            completed_at: value.completed_at,
+            status: value.status,
+        }
     }
 }
 
 /// The outgoing SMS message to be sent to a target number.
-#[derive(Serialize, PartialEq, Default, Debug, Clone)]
+/// With the `zeroize` feature, wrap it in `zeroize::Zeroizing` to scrub the content on drop.
+#[derive(Serialize, Deserialize, PartialEq, Default, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
 pub struct SmsOutgoingMessage {
     /// The target phone number, this should be in international format.
     pub to: String,
@@ -114,15 +274,32 @@ impl SmsOutgoingMessage {
         if self.flash.unwrap_or(false) {
             return 0;
         }
-        self.validity_period.unwrap_or(167) // 24hr
+        self.validity_period
+            .unwrap_or(crate::limits::DEFAULT_VALIDITY)
+    }
+}
+impl crate::validate::Validate for SmsOutgoingMessage {
+    fn validate(&self) -> Result<(), crate::validate::ValidationError> {
+        crate::validate::phone_number("to", &self.to)?;
+        crate::validate::not_blank("content", &self.content)?;
+        if !crate::limits::fits_multipart(&self.content) {
+            return Err(crate::validate::ValidationError::new(
+                "content",
+                format!(
+                    "must fit in {} message parts",
+                    crate::limits::MAX_MULTIPART_PARTS
+                ),
+            ));
+        }
+        crate::validate::timeout(self.timeout)
     }
 }
 impl From<&SmsOutgoingMessage> for SmsMessage {
     fn from(outgoing: &SmsOutgoingMessage) -> Self {
         SmsMessage {
             message_id: None,
-            phone_number: outgoing.to.clone(),
-            message_content: outgoing.content.clone(),
+            phone_number: outgoing.to.as_str().into(),
+            message_content: outgoing.content.as_str().into(),
             message_reference: None,
             is_outgoing: true,
             status: None,
@@ -132,8 +309,45 @@ impl From<&SmsOutgoingMessage> for SmsMessage {
     }
 }
 
+/// Response returned after sending an SMS message.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct SmsSendResponse {
+    /// The unique ID assigned to the already sent message.
+    pub message_id: i64,
+
+    /// Reference ID for tracking the message.
+    pub reference_id: u8,
+}
+
+/// Combine an outgoing message and send response into a dummy `SmsStoredMessage`.
+impl From<(SmsOutgoingMessage, SmsSendResponse)> for SmsMessage {
+    fn from(value: (SmsOutgoingMessage, SmsSendResponse)) -> SmsMessage {
+        SmsMessage {
+            message_id: Some(value.1.message_id),
+            phone_number: value.0.to.into(),
+            message_content: value.0.content.into(),
+            message_reference: Some(value.1.reference_id),
+            is_outgoing: true,
+            status: None,
+            created_at: None,
+            completed_at: None,
+        }
+    }
+}
+
 /// An incoming message from the Modem.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
 pub struct SmsIncomingMessage {
     /// The incoming sender address. This could also be an alphanumeric sender name.
     /// This is usually for registered businesses or carrier messages.
@@ -149,8 +363,8 @@ impl From<&SmsIncomingMessage> for SmsMessage {
     fn from(incoming: &SmsIncomingMessage) -> Self {
         SmsMessage {
             message_id: None,
-            phone_number: incoming.phone_number.clone(),
-            message_content: incoming.content.clone(),
+            phone_number: incoming.phone_number.as_str().into(),
+            message_content: incoming.content.as_str().into(),
             message_reference: None,
             is_outgoing: false,
             status: None,
@@ -161,8 +375,18 @@ impl From<&SmsIncomingMessage> for SmsMessage {
 }
 
 /// A received or stored delivery report.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::SimpleObject))]
 pub struct SmsDeliveryReport {
     /// Unique identifier for this delivery report.
     pub report_id: Option<i64>,
@@ -176,9 +400,27 @@ pub struct SmsDeliveryReport {
     /// Unix timestamp when this report was created.
     pub created_at: Option<u32>,
 }
+impl SmsDeliveryReport {
+    /// Get when the report was created.
+    #[must_use]
+    pub fn created_timestamp(&self) -> Option<Timestamp> {
+        self.created_at
+            .map(|seconds| Timestamp::from_unix_seconds(u64::from(seconds)))
+    }
+}
 
 /// A partial message delivery report, as it comes from the modem.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::SimpleObject))]
 pub struct SmsPartialDeliveryReport {
     /// The target phone number that received the message (and has now sent back a delivery report).
     pub phone_number: String,
@@ -191,7 +433,22 @@ pub struct SmsPartialDeliveryReport {
 }
 
 /// A general category of status message delivery status reports.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
 pub enum SmsDeliveryReportStatusCategory {
     /// The message has been sent, however not yet delivered.
     Sent,
@@ -209,24 +466,93 @@ impl From<u8> for SmsDeliveryReportStatusCategory {
     fn from(value: u8) -> Self {
         match value {
             0x00 => SmsDeliveryReportStatusCategory::Received, // Received by SME
-            0x01..=0x02 => SmsDeliveryReportStatusCategory::Sent, // Forwarded/Replaced
-            0x03..=0x1F => SmsDeliveryReportStatusCategory::Sent, // Reserved/SC-specific success
+            // Forwarded/Replaced, Reserved/SC-specific success
+            0x01..=0x1F => SmsDeliveryReportStatusCategory::Sent,
             0x20..=0x3F => SmsDeliveryReportStatusCategory::Retrying,
-            0x40..=0x6F => SmsDeliveryReportStatusCategory::Failed,
             _ => SmsDeliveryReportStatusCategory::Failed,
         }
     }
 }
-impl std::fmt::Display for SmsDeliveryReportStatusCategory {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(match self {
+impl SmsDeliveryReportStatusCategory {
+    /// Get the name of the category, as displayed and stored in databases.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
             SmsDeliveryReportStatusCategory::Sent => "Sent",
             SmsDeliveryReportStatusCategory::Received => "Received",
             SmsDeliveryReportStatusCategory::Retrying => "Retrying",
             SmsDeliveryReportStatusCategory::Failed => "Failed",
+        }
+    }
+
+    /// Get a category from its name.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Sent" => Some(SmsDeliveryReportStatusCategory::Sent),
+            "Received" => Some(SmsDeliveryReportStatusCategory::Received),
+            "Retrying" => Some(SmsDeliveryReportStatusCategory::Retrying),
+            "Failed" => Some(SmsDeliveryReportStatusCategory::Failed),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SmsDeliveryReportStatusCategory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl core::str::FromStr for SmsDeliveryReportStatusCategory {
+    type Err = String;
+
+    /// Parse a category from its displayed name.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s.trim())
+            .ok_or_else(|| format!("Invalid delivery report status category: '{s}'"))
+    }
+}
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::ToSql for SmsDeliveryReportStatusCategory {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        Ok(rusqlite::types::ToSqlOutput::from(self.as_str()))
+    }
+}
+#[cfg(feature = "rusqlite")]
+impl rusqlite::types::FromSql for SmsDeliveryReportStatusCategory {
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        let value = value.as_str()?;
+        Self::from_name(value).ok_or_else(|| {
+            rusqlite::types::FromSqlError::Other(
+                format!("Invalid delivery report status category: {value}").into(),
+            )
         })
     }
 }
+#[cfg(feature = "diesel")]
+impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for SmsDeliveryReportStatusCategory
+where
+    DB: diesel::backend::Backend,
+    str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        self.as_str().to_sql(out)
+    }
+}
+#[cfg(feature = "diesel")]
+impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB>
+    for SmsDeliveryReportStatusCategory
+where
+    DB: diesel::backend::Backend,
+    String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        let value = String::from_sql(bytes)?;
+        Self::from_name(&value)
+            .ok_or_else(|| format!("Invalid delivery report status category: {value}").into())
+    }
+}
 impl From<&SmsDeliveryReport> for SmsDeliveryReportStatusCategory {
     fn from(value: &SmsDeliveryReport) -> Self {
         SmsDeliveryReportStatusCategory::from(value.status)
@@ -240,6 +566,8 @@ impl From<&SmsPartialDeliveryReport> for SmsDeliveryReportStatusCategory {
 
 /// The sms message multipart header.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
 pub struct SmsMultipartHeader {
     /// Modem assigned message send reference (overflows).
     pub message_reference: u8,
@@ -264,3 +592,199 @@ impl TryFrom<Vec<u8>> for SmsMultipartHeader {
         })
     }
 }
+
+/// SMS storage memory, as used by AT+CPMS.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
+pub enum SmsStorage {
+    /// SIM card storage (SM).
+    Sim,
+
+    /// Modem storage (ME).
+    Modem,
+
+    /// Combined SIM and modem storage (MT).
+    Combined,
+}
+impl core::fmt::Display for SmsStorage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            SmsStorage::Sim => "Sim",
+            SmsStorage::Modem => "Modem",
+            SmsStorage::Combined => "Combined",
+        })
+    }
+}
+impl SmsStorage {
+    /// Get the AT+CPMS memory name.
+    #[must_use]
+    pub const fn to_memory(self) -> &'static str {
+        match self {
+            SmsStorage::Sim => "SM",
+            SmsStorage::Modem => "ME",
+            SmsStorage::Combined => "MT",
+        }
+    }
+}
+impl TryFrom<&str> for SmsStorage {
+    type Error = String;
+
+    /// Convert an AT+CPMS memory name into an `SmsStorage`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().trim_matches('"') {
+            "SM" => Ok(SmsStorage::Sim),
+            "ME" => Ok(SmsStorage::Modem),
+            "MT" => Ok(SmsStorage::Combined),
+            _ => Err(format!("Invalid SMS storage: '{value}'")),
+        }
+    }
+}
+
+/// Usage of an SMS storage memory.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct SmsStorageStatus {
+    /// The storage memory.
+    pub storage: SmsStorage,
+
+    /// The amount of messages stored.
+    pub used: u16,
+
+    /// The total amount of messages that can be stored.
+    pub total: u16,
+}
+impl SmsStorageStatus {
+    /// Get the used percentage of the storage (0-100).
+    #[must_use]
+    pub fn usage_percent(&self) -> u8 {
+        if self.total == 0 {
+            return 100;
+        }
+        let percent = u32::from(self.used.min(self.total)) * 100 / u32::from(self.total);
+        u8::try_from(percent).unwrap_or(100)
+    }
+
+    /// Returns true if no more messages can be stored.
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.used >= self.total
+    }
+}
+
+/// An error returned by the HTTP or websocket interfaces.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct ApiError {
+    /// The category of error.
+    pub code: ApiErrorCode,
+
+    /// A human readable description of the error.
+    pub message: String,
+}
+impl core::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)
+    }
+}
+impl core::error::Error for ApiError {}
+
+/// Error codes shared by the HTTP and websocket interfaces.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
+pub enum ApiErrorCode {
+    /// The request was malformed or had invalid values.
+    BadRequest,
+
+    /// The client has not authenticated.
+    Unauthorized,
+
+    /// The client is authenticated but not allowed to perform the request.
+    Forbidden,
+
+    /// The requested resource does not exist.
+    NotFound,
+
+    /// Too many requests have been made in a short period of time.
+    RateLimited,
+
+    /// The request did not complete within its timeout.
+    Timeout,
+
+    /// The modem is not online, so the request cannot be handled.
+    ModemUnavailable,
+
+    /// The request requires a server feature that is not enabled.
+    Unsupported,
+
+    /// An unexpected server side error occurred.
+    Internal,
+
+    /// An error code this version doesn't know, treated as an internal error.
+    #[serde(other)]
+    Unknown,
+}
+impl core::fmt::Display for ApiErrorCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ApiErrorCode::BadRequest => "BadRequest",
+            ApiErrorCode::Unauthorized => "Unauthorized",
+            ApiErrorCode::Forbidden => "Forbidden",
+            ApiErrorCode::NotFound => "NotFound",
+            ApiErrorCode::RateLimited => "RateLimited",
+            ApiErrorCode::Timeout => "Timeout",
+            ApiErrorCode::ModemUnavailable => "ModemUnavailable",
+            ApiErrorCode::Unsupported => "Unsupported",
+            ApiErrorCode::Internal => "Internal",
+            ApiErrorCode::Unknown => "Unknown",
+        })
+    }
+}
+impl ApiErrorCode {
+    /// Get the equivalent HTTP status code.
+    #[must_use]
+    pub const fn http_status(self) -> u16 {
+        match self {
+            ApiErrorCode::BadRequest => 400,
+            ApiErrorCode::Unauthorized => 401,
+            ApiErrorCode::Forbidden => 403,
+            ApiErrorCode::NotFound => 404,
+            ApiErrorCode::RateLimited => 429,
+            ApiErrorCode::Timeout => 504,
+            ApiErrorCode::ModemUnavailable => 503,
+            ApiErrorCode::Unsupported => 501,
+            ApiErrorCode::Internal | ApiErrorCode::Unknown => 500,
+        }
+    }
+}
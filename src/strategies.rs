@@ -0,0 +1,173 @@
+//! Proptest strategies generating realistic values, so downstream crates can property test
+//! round trips without writing their own generators.
+
+use crate::sms::{SmsDeliveryReport, SmsMessage};
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+/// Unix timestamps in seconds between 2017 and 2033.
+const UNIX_SECONDS: core::ops::Range<u32> = 1_500_000_000..2_000_000_000;
+
+/// Generate an E.164 phone number, eg: "+447700900123".
+pub fn phone_number() -> impl Strategy<Value = String> {
+    "\\+[1-9][0-9]{6,14}"
+}
+
+/// Generate message content of up to 160 printable characters.
+pub fn message_content() -> impl Strategy<Value = String> {
+    "\\PC{1,160}"
+}
+
+/// Generate a TP-Status of a report that isn't final, the message is still being sent or retried.
+pub fn pending_delivery_status() -> impl Strategy<Value = u8> {
+    0x01..=0x3Fu8
+}
+
+/// Generate a TP-Status of a final report, the message was either received or permanently failed.
+pub fn final_delivery_status() -> impl Strategy<Value = u8> {
+    prop_oneof![Just(0x00u8), 0x40..=0x7Fu8]
+}
+
+/// Generate the TP-Status codes of every delivery report for one message,
+/// zero or more pending statuses followed by a final status.
+pub fn delivery_status_sequence() -> impl Strategy<Value = Vec<u8>> {
+    (
+        vec(pending_delivery_status(), 0..4),
+        final_delivery_status(),
+    )
+        .prop_map(|(mut statuses, last)| {
+            statuses.push(last);
+            statuses
+        })
+}
+
+/// Generate the delivery reports for one message in the order they are received,
+/// with increasing creation times and only the last report marked final.
+pub fn delivery_reports() -> impl Strategy<Value = Vec<SmsDeliveryReport>> {
+    (delivery_status_sequence(), UNIX_SECONDS, 1..3600u32).prop_map(
+        |(statuses, start, interval)| {
+            let last = statuses.len() - 1;
+            statuses
+                .into_iter()
+                .enumerate()
+                .map(|(i, status)| SmsDeliveryReport {
+                    report_id: None,
+                    status,
+                    is_final: i == last,
+                    created_at: u32::try_from(i)
+                        .ok()
+                        .map(|i| start.saturating_add(i * interval)),
+                })
+                .collect()
+        },
+    )
+}
+
+/// Generate a stored incoming or outgoing message.
+/// Only outgoing messages have a reference, and completed outgoing messages
+/// have a final status and a completion time after their creation time.
+pub fn sms_message() -> impl Strategy<Value = SmsMessage> {
+    (
+        proptest::option::of(1..i64::MAX),
+        phone_number(),
+        message_content(),
+        any::<bool>(),
+        any::<u8>(),
+        UNIX_SECONDS,
+        proptest::option::of((final_delivery_status(), 1..86_400u32)),
+    )
+        .prop_map(
+            |(
+                message_id,
+                phone_number,
+                message_content,
+                is_outgoing,
+                reference,
+                created_at,
+                completion,
+            )| {
+                let completion = completion.filter(|_| is_outgoing);
+                SmsMessage {
+                    message_id,
+                    phone_number: phone_number.into(),
+                    message_content: message_content.into(),
+                    message_reference: is_outgoing.then_some(reference),
+                    is_outgoing,
+                    created_at: Some(created_at),
+                    completed_at: completion.map(|(_, delay)| created_at + delay),
+                    status: completion.map(|(status, _)| status),
+                }
+            },
+        )
+}
+
+/// Generate a point away from the poles, as a 3D fix at any time.
+#[cfg(feature = "gnss")]
+fn fix_start() -> impl Strategy<Value = (crate::gnss::GeoPoint, u64)> {
+    (
+        -80.0..80.0f64,
+        -180.0..180.0f64,
+        u64::from(UNIX_SECONDS.start) * 1000..u64::from(UNIX_SECONDS.end) * 1000,
+    )
+        .prop_map(|(latitude, longitude, millis)| {
+            (crate::gnss::GeoPoint::new(latitude, longitude), millis)
+        })
+}
+
+/// Generate a report with a 3D fix.
+#[cfg(feature = "gnss")]
+pub fn position_report() -> impl Strategy<Value = crate::gnss::PositionReport> {
+    (fix_start(), 0.0..30.0f32, 0.0..360.0f32)
+        .prop_map(|((point, millis), speed, course)| fixed_report(point, millis, speed, course))
+}
+
+/// Generate a track of consecutive 3D fixes one second apart, moving at up to 30 m/s.
+#[cfg(feature = "gnss")]
+pub fn track(
+    len: impl Into<proptest::collection::SizeRange>,
+) -> impl Strategy<Value = crate::gnss::Track> {
+    (fix_start(), vec((0.0..30.0f32, 0.0..360.0f32), len)).prop_map(
+        |((mut point, mut millis), steps)| {
+            steps
+                .into_iter()
+                .map(|(speed, course)| {
+                    let report = fixed_report(point, millis, speed, course);
+                    point = point.destination(f64::from(course), f64::from(speed));
+                    millis += 1000;
+                    report
+                })
+                .collect::<Vec<_>>()
+                .into()
+        },
+    )
+}
+
+/// Create a 3D fix report at a point and time.
+#[cfg(feature = "gnss")]
+fn fixed_report(
+    point: crate::gnss::GeoPoint,
+    millis: u64,
+    speed: f32,
+    course: f32,
+) -> crate::gnss::PositionReport {
+    let (year, month, day, hour, minute, second, millis) =
+        crate::timestamp::civil_from_millis(millis);
+    crate::gnss::PositionReport {
+        run_status: true,
+        fix_status: true,
+        utc_time: format!("{year:04}{month:02}{day:02}{hour:02}{minute:02}{second:02}.{millis:03}"),
+        latitude: Some(point.latitude),
+        longitude: Some(point.longitude),
+        msl_altitude: Some(50.0),
+        ground_speed: Some(speed),
+        ground_course: Some(course),
+        fix_mode: crate::gnss::FixStatus::Fix3D,
+        hdop: Some(1.0),
+        pdop: Some(1.5),
+        vdop: Some(1.1),
+        gps_in_view: Some(10),
+        gnss_used: Some(8),
+        glonass_in_view: Some(6),
+        satellites: Vec::new(),
+    }
+}
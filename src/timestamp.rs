@@ -0,0 +1,167 @@
+//! A single point in time type for every timestamp in the crate.
+//!
+//! Fields keep their wire types, Unix seconds as `u32` or Unix milliseconds as `u64`,
+//! and expose them as a `Timestamp` through accessors, eg: `message.created_timestamp()`.
+//! With the `chrono` or `time` features, a `Timestamp` converts to and from their UTC types.
+
+use serde::{Deserialize, Serialize};
+
+/// A point in time, stored as Unix milliseconds. Serialized as the plain integer.
+#[derive(
+    Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Debug, Clone, Copy,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[serde(transparent)]
+pub struct Timestamp(u64);
+impl Timestamp {
+    /// The Unix epoch, 1970-01-01T00:00:00Z.
+    pub const UNIX_EPOCH: Self = Self(0);
+
+    /// Create a timestamp from Unix milliseconds.
+    #[must_use]
+    pub const fn from_unix_millis(millis: u64) -> Self {
+        Self(millis)
+    }
+
+    /// Create a timestamp from Unix seconds, saturating on overflow.
+    #[must_use]
+    pub const fn from_unix_seconds(seconds: u64) -> Self {
+        Self(seconds.saturating_mul(1000))
+    }
+
+    /// Get the timestamp as Unix milliseconds.
+    #[must_use]
+    pub const fn as_unix_millis(self) -> u64 {
+        self.0
+    }
+
+    /// Get the timestamp as Unix seconds, rounded down.
+    #[must_use]
+    pub const fn as_unix_seconds(self) -> u64 {
+        self.0 / 1000
+    }
+
+    /// Get the current time.
+    ///
+    /// Not available on `wasm32-unknown-unknown`, which has no system clock.
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    #[must_use]
+    pub fn now() -> Self {
+        Self::from(std::time::SystemTime::now())
+    }
+
+    /// Get the time elapsed since an earlier timestamp, or zero if it's later.
+    #[must_use]
+    pub const fn saturating_duration_since(self, earlier: Self) -> core::time::Duration {
+        core::time::Duration::from_millis(self.0.saturating_sub(earlier.0))
+    }
+}
+impl core::fmt::Display for Timestamp {
+    /// Format as an ISO 8601 UTC date time, eg: "2024-01-02T03:04:05.678Z".
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (year, month, day, hour, minute, second, millis) = civil_from_millis(self.0);
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}.{millis:03}Z"
+        )
+    }
+}
+#[cfg(feature = "std")]
+impl From<std::time::SystemTime> for Timestamp {
+    /// Convert a `SystemTime`, saturating to the epoch or `u64::MAX` milliseconds.
+    fn from(value: std::time::SystemTime) -> Self {
+        Self(
+            value
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX)),
+        )
+    }
+}
+#[cfg(feature = "std")]
+impl From<Timestamp> for std::time::SystemTime {
+    fn from(value: Timestamp) -> Self {
+        std::time::UNIX_EPOCH + std::time::Duration::from_millis(value.0)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<Timestamp> for chrono::DateTime<chrono::Utc> {
+    type Error = alloc::string::String;
+
+    fn try_from(value: Timestamp) -> Result<Self, Self::Error> {
+        i64::try_from(value.0)
+            .ok()
+            .and_then(chrono::DateTime::from_timestamp_millis)
+            .ok_or_else(|| alloc::format!("Timestamp out of range: {}", value.0))
+    }
+}
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for Timestamp {
+    type Error = alloc::string::String;
+
+    /// Convert a date time, failing if it's before the Unix epoch.
+    fn try_from(value: chrono::DateTime<chrono::Utc>) -> Result<Self, Self::Error> {
+        u64::try_from(value.timestamp_millis())
+            .map(Self)
+            .map_err(|_| alloc::format!("Date time before the Unix epoch: {value}"))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<Timestamp> for time::OffsetDateTime {
+    type Error = alloc::string::String;
+
+    fn try_from(value: Timestamp) -> Result<Self, Self::Error> {
+        time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(value.0) * 1_000_000)
+            .map_err(|_| alloc::format!("Timestamp out of range: {}", value.0))
+    }
+}
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for Timestamp {
+    type Error = alloc::string::String;
+
+    /// Convert a date time in any offset, failing if it's before the Unix epoch.
+    fn try_from(value: time::OffsetDateTime) -> Result<Self, Self::Error> {
+        u64::try_from(value.unix_timestamp_nanos() / 1_000_000)
+            .map(Self)
+            .map_err(|_| alloc::format!("Date time before the Unix epoch: {value}"))
+    }
+}
+
+/// Split a Unix timestamp in milliseconds into a UTC date and time,
+/// as `(year, month, day, hour, minute, second, millis)`.
+pub(crate) fn civil_from_millis(millis: u64) -> (u64, u64, u64, u64, u64, u64, u64) {
+    let days = millis / 86_400_000;
+    let time = millis % 86_400_000;
+
+    // Civil date from days since the epoch, see: https://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    (
+        year,
+        month,
+        day,
+        time / 3_600_000,
+        time / 60_000 % 60,
+        time / 1000 % 60,
+        time % 1000,
+    )
+}
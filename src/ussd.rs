@@ -0,0 +1,105 @@
+//! USSD (Unstructured Supplementary Service Data) session types.
+
+use serde::{Deserialize, Serialize};
+
+/// Server assigned identifier for an interactive USSD session.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(as = "u32"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[serde(transparent)]
+pub struct UssdSessionId(pub u32);
+impl std::fmt::Display for UssdSessionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The network status of a USSD session, as reported in `+CUSD`.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[non_exhaustive]
+pub enum UssdSessionStatus {
+    /// No further user action is required, the session has ended.
+    Completed,
+
+    /// The network is waiting for a reply, the session is still open.
+    ActionRequired,
+
+    /// The session was terminated by the network.
+    Terminated,
+
+    /// Another local client has responded.
+    OtherClientResponded,
+
+    /// The operation is not supported by the network.
+    NotSupported,
+
+    /// The network did not respond in time.
+    Timeout,
+}
+impl core::fmt::Display for UssdSessionStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            UssdSessionStatus::Completed => "Completed",
+            UssdSessionStatus::ActionRequired => "ActionRequired",
+            UssdSessionStatus::Terminated => "Terminated",
+            UssdSessionStatus::OtherClientResponded => "OtherClientResponded",
+            UssdSessionStatus::NotSupported => "NotSupported",
+            UssdSessionStatus::Timeout => "Timeout",
+        })
+    }
+}
+impl UssdSessionStatus {
+    /// Returns true if the session remains open and can be replied to.
+    #[must_use]
+    pub const fn is_open(self) -> bool {
+        matches!(self, UssdSessionStatus::ActionRequired)
+    }
+}
+impl TryFrom<u8> for UssdSessionStatus {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(UssdSessionStatus::Completed),
+            1 => Ok(UssdSessionStatus::ActionRequired),
+            2 => Ok(UssdSessionStatus::Terminated),
+            3 => Ok(UssdSessionStatus::OtherClientResponded),
+            4 => Ok(UssdSessionStatus::NotSupported),
+            5 => Ok(UssdSessionStatus::Timeout),
+            _ => Err(format!("Invalid USSD session status: {value}")),
+        }
+    }
+}
+
+/// A USSD response received from the network.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+    feature = "binary-codec",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct UssdResponse {
+    /// The session this response belongs to.
+    pub session_id: UssdSessionId,
+
+    /// The session status after this response.
+    pub status: UssdSessionStatus,
+
+    /// The decoded response text, if the network sent any.
+    pub message: Option<String>,
+}
@@ -0,0 +1,96 @@
+//! Request validation, so servers can reject bad values at the boundary with one call.
+//!
+//! eg: `message.validate().map_err(ApiError::from)?;`
+
+use crate::limits::MAX_PHONE_LEN;
+use alloc::string::String;
+
+/// A value that can be checked before it's acted on.
+pub trait Validate {
+    /// Check every value, returning the first that's invalid.
+    ///
+    /// # Errors
+    /// Returns a `ValidationError` naming the invalid field.
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+/// An invalid value, with the field it was found in.
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ValidationError {
+    /// The name of the invalid field.
+    pub field: &'static str,
+
+    /// A human readable description of why the value is invalid.
+    pub message: String,
+}
+impl ValidationError {
+    /// Create a validation error for a field.
+    #[must_use]
+    pub fn new(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            message: message.into(),
+        }
+    }
+}
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid {}: {}", self.field, self.message)
+    }
+}
+impl core::error::Error for ValidationError {}
+impl From<ValidationError> for crate::sms::ApiError {
+    fn from(error: ValidationError) -> Self {
+        use alloc::string::ToString;
+
+        crate::sms::ApiError {
+            code: crate::sms::ApiErrorCode::BadRequest,
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Check a phone number is an optional `+` followed by 3 to 15 digits,
+/// covering both international numbers and short codes.
+///
+/// # Errors
+/// Returns a `ValidationError` for the given field if the number is invalid.
+pub fn phone_number(field: &'static str, value: &str) -> Result<(), ValidationError> {
+    let digits = value.strip_prefix('+').unwrap_or(value);
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ValidationError::new(field, "must only contain digits"));
+    }
+    if !(3..=MAX_PHONE_LEN).contains(&digits.len()) {
+        return Err(ValidationError::new(
+            field,
+            alloc::format!("must have 3 to {MAX_PHONE_LEN} digits"),
+        ));
+    }
+    Ok(())
+}
+
+/// Check an optional timeout isn't zero.
+pub(crate) fn timeout(value: Option<u32>) -> Result<(), ValidationError> {
+    match value {
+        Some(0) => Err(ValidationError::new("timeout", "must be greater than zero")),
+        _ => Ok(()),
+    }
+}
+
+/// Check a string isn't empty or only whitespace.
+pub(crate) fn not_blank(field: &'static str, value: &str) -> Result<(), ValidationError> {
+    if value.trim().is_empty() {
+        return Err(ValidationError::new(field, "must not be empty"));
+    }
+    Ok(())
+}
+
+/// Check a URL uses http or https.
+#[cfg(feature = "http")]
+pub(crate) fn http_url(field: &'static str, value: &str) -> Result<(), ValidationError> {
+    if !(value.starts_with("http://") || value.starts_with("https://")) {
+        return Err(ValidationError::new(field, "must be an http or https URL"));
+    }
+    Ok(())
+}
@@ -0,0 +1,105 @@
+//! JavaScript bindings, so browser clients parse websocket frames with the same logic as native clients.
+//! Values cross the boundary as plain objects in their regular JSON representation.
+//!
+//! Functions that read the current time (eg: `EventEnvelope::new`, `Timestamp::now` and
+//! `TimedPosition::age`) are not available on `wasm32-unknown-unknown`, as it has no system clock.
+//! Timestamps should be supplied by the caller instead, eg: `TimedPosition::age_at`.
+
+use crate::sms::SmsOutgoingMessage;
+use crate::websocket::{WebsocketCommand, WebsocketFrame, WebsocketMessage, WebsocketSubscription};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use wasm_bindgen::prelude::*;
+
+/// Convert a value into a plain JS object.
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Convert a plain JS object into a value.
+fn from_js<T: DeserializeOwned>(value: JsValue) -> Result<T, JsError> {
+    serde_wasm_bindgen::from_value(value).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Parse a websocket text frame.
+///
+/// # Errors
+/// Returns an error if the JSON is not a valid frame.
+#[wasm_bindgen(js_name = parseWebsocketFrame)]
+pub fn parse_websocket_frame(json: &str) -> Result<JsValue, JsError> {
+    let frame: WebsocketFrame<WebsocketMessage> = serde_json::from_str(json)?;
+    to_js(&frame)
+}
+
+/// Parse a `MessagePack` websocket binary frame.
+///
+/// # Errors
+/// Returns an error if the bytes are not a valid encoded frame.
+#[cfg(feature = "binary")]
+#[wasm_bindgen(js_name = decodeWebsocketFrame)]
+pub fn decode_websocket_frame(bytes: &[u8]) -> Result<JsValue, JsError> {
+    let frame = WebsocketFrame::<WebsocketMessage>::from_msgpack(bytes)?;
+    to_js(&frame)
+}
+
+/// Parse a websocket message without a frame wrapper.
+///
+/// # Errors
+/// Returns an error if the JSON is not a valid message.
+#[wasm_bindgen(js_name = parseWebsocketMessage)]
+pub fn parse_websocket_message(json: &str) -> Result<JsValue, JsError> {
+    let message: WebsocketMessage = serde_json::from_str(json)?;
+    to_js(&message)
+}
+
+/// Get the event kind name of a websocket message object, eg: "incoming".
+///
+/// # Errors
+/// Returns an error if the object is not a valid message.
+#[wasm_bindgen(js_name = websocketMessageKind)]
+pub fn websocket_message_kind(message: JsValue) -> Result<Option<String>, JsError> {
+    let message: WebsocketMessage = from_js(message)?;
+    Ok(message.kind().map(|kind| kind.as_str().to_string()))
+}
+
+/// Validate a websocket command object and encode it as a text frame.
+///
+/// # Errors
+/// Returns an error if the object is not a valid command.
+#[wasm_bindgen(js_name = encodeWebsocketCommand)]
+pub fn encode_websocket_command(command: JsValue, id: Option<u64>) -> Result<String, JsError> {
+    let command: WebsocketCommand = from_js(command)?;
+    let frame = match id {
+        Some(id) => WebsocketFrame::with_id(id, command),
+        None => WebsocketFrame::new(command),
+    };
+    Ok(serde_json::to_string(&frame)?)
+}
+
+/// Create an outgoing message object.
+///
+/// # Errors
+/// Returns an error if the message cannot be converted.
+#[wasm_bindgen(js_name = outgoingMessage)]
+pub fn outgoing_message(to: String, content: String) -> Result<JsValue, JsError> {
+    to_js(&SmsOutgoingMessage::simple_message(to, content))
+}
+
+/// Check if a subscription object allows a websocket message object.
+/// Messages that aren't events are always allowed.
+///
+/// # Errors
+/// Returns an error if either object is invalid.
+#[wasm_bindgen(js_name = subscriptionAllows)]
+pub fn subscription_allows(
+    subscription: JsValue,
+    message: JsValue,
+    client_id: Option<u64>,
+) -> Result<bool, JsError> {
+    let subscription: WebsocketSubscription = from_js(subscription)?;
+    let message: WebsocketMessage = from_js(message)?;
+    Ok(crate::events::Event::try_from(message)
+        .map_or(true, |event| subscription.allows(&event, client_id)))
+}
@@ -0,0 +1,1100 @@
+//! Websocket interface related message and command types.
+
+use serde::{Deserialize, Serialize, de};
+
+/// The current websocket protocol version.
+pub const PROTOCOL_VERSION: u16 = 1;
+
+/// The oldest websocket protocol version that is still supported.
+pub const MIN_PROTOCOL_VERSION: u16 = 1;
+
+/// A websocket frame, carrying an optional correlation id alongside its payload.
+/// When a client sends a command with an id, the server includes the same id
+/// on every frame sent in reply to it, allowing concurrent commands to be matched.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct WebsocketFrame<T> {
+    /// The correlation id, chosen by the client for commands.
+    /// This is None for unsolicited server frames, eg: events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    /// Monotonic sequence number assigned by the server to frames it sends.
+    /// This is used to resume a connection without missing any frames.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<u64>,
+
+    /// The channel this frame belongs to, when the server hosts multiple
+    /// logically separate SMS lines. None for single channel servers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+
+    /// The frame payload, either a `WebsocketCommand` or `WebsocketMessage`.
+    pub payload: T,
+}
+impl<T> WebsocketFrame<T> {
+    /// Create a new frame without a correlation id.
+    pub fn new(payload: T) -> Self {
+        Self {
+            id: None,
+            sequence: None,
+            channel: None,
+            payload,
+        }
+    }
+
+    /// Create a new frame with a correlation id.
+    pub fn with_id(id: u64, payload: T) -> Self {
+        Self {
+            id: Some(id),
+            sequence: None,
+            channel: None,
+            payload,
+        }
+    }
+
+    /// Set the server assigned sequence number.
+    #[must_use]
+    pub fn with_sequence(mut self, sequence: u64) -> Self {
+        self.sequence = Some(sequence);
+        self
+    }
+
+    /// Set the channel this frame belongs to.
+    #[must_use]
+    pub fn with_channel(mut self, channel: impl Into<String>) -> Self {
+        self.channel = Some(channel.into());
+        self
+    }
+
+    /// Create a reply frame using the same correlation id as this frame.
+    pub fn reply<R>(&self, payload: R) -> WebsocketFrame<R> {
+        WebsocketFrame {
+            id: self.id,
+            sequence: None,
+            channel: self.channel.clone(),
+            payload,
+        }
+    }
+}
+
+#[cfg(feature = "binary")]
+impl<T: Serialize> WebsocketFrame<T> {
+    /// Encode the frame as a `MessagePack` binary frame.
+    ///
+    /// # Errors
+    /// Returns an error if the payload cannot be serialized.
+    pub fn to_msgpack(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec_named(self)
+    }
+}
+#[cfg(feature = "binary")]
+impl<T: serde::de::DeserializeOwned> WebsocketFrame<T> {
+    /// Decode a frame from a `MessagePack` binary frame.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid encoded frame.
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Self, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(bytes)
+    }
+}
+
+/// The set of events a connection is subscribed to.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct WebsocketSubscription {
+    /// The filter selecting which events are received.
+    #[serde(flatten)]
+    pub filter: crate::events::EventFilter,
+
+    /// Only receive message, delivery report and call events for these phone numbers.
+    /// If None, events for all phone numbers are received.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone_numbers: Option<Vec<String>>,
+
+    /// Should outgoing message events for messages sent by this connection be suppressed?
+    #[serde(default)]
+    pub suppress_own_echo: bool,
+
+    /// Require events to be acknowledged, redelivering them until they are.
+    /// Events are then sent as `EventEnvelope` messages so they can be acked by id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redelivery: Option<RedeliveryPolicy>,
+
+    /// Seconds between `Digest` events summarizing activity, for clients that wake rarely.
+    /// If None, no digests are sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest_interval: Option<u32>,
+}
+impl WebsocketSubscription {
+    /// Create a subscription for a set of `EventKind`s, for all phone numbers.
+    #[must_use]
+    pub fn new(events: &[crate::events::EventKind]) -> Self {
+        Self::from_filter(crate::events::EventFilter::new(events.into()))
+    }
+
+    /// Create a subscription from an event filter.
+    #[must_use]
+    pub fn from_filter(filter: crate::events::EventFilter) -> Self {
+        Self {
+            filter,
+            phone_numbers: None,
+            suppress_own_echo: false,
+            redelivery: None,
+            digest_interval: None,
+        }
+    }
+
+    /// Only receive events relating to the given phone numbers.
+    #[must_use]
+    pub fn with_phone_numbers(mut self, phone_numbers: Vec<String>) -> Self {
+        self.phone_numbers = Some(phone_numbers);
+        self
+    }
+
+    /// Set whether the echo of this connection's own outgoing messages is suppressed.
+    #[must_use]
+    pub fn with_suppress_own_echo(mut self, suppress_own_echo: bool) -> Self {
+        self.suppress_own_echo = suppress_own_echo;
+        self
+    }
+
+    /// Require events to be acknowledged with `EventAck`, for at-least-once delivery.
+    #[must_use]
+    pub fn with_redelivery(mut self, redelivery: RedeliveryPolicy) -> Self {
+        self.redelivery = Some(redelivery);
+        self
+    }
+
+    /// Receive a `Digest` event every `seconds`.
+    #[must_use]
+    pub fn with_digest_interval(mut self, seconds: u32) -> Self {
+        self.digest_interval = Some(seconds);
+        self
+    }
+
+    /// Check if an event should be sent to this subscription, for the connection with `client_id`.
+    #[must_use]
+    pub fn allows(&self, event: &crate::events::Event, client_id: Option<u64>) -> bool {
+        use crate::events::Event;
+
+        if !self.filter.matches(event) {
+            return false;
+        }
+        if let Event::OutgoingMessage {
+            origin_client_id: Some(origin),
+            ..
+        } = event
+            && self.suppress_own_echo
+            && client_id == Some(*origin)
+        {
+            return false;
+        }
+        let Some(phone_numbers) = &self.phone_numbers else {
+            return true;
+        };
+        match event.phone_number() {
+            Some(phone_number) => phone_numbers.iter().any(|number| number == phone_number),
+            None => !matches!(event, Event::IncomingCall(_)),
+        }
+    }
+}
+
+/// How unacknowledged events are redelivered, for at-least-once delivery.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct RedeliveryPolicy {
+    /// Milliseconds to wait for an `EventAck` before redelivering.
+    pub ack_timeout: u32,
+
+    /// The maximum amount of redeliveries before the event is dropped.
+    /// None redelivers until acknowledged or the pending list is full.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_redeliveries: Option<u8>,
+}
+impl Default for RedeliveryPolicy {
+    fn default() -> Self {
+        Self {
+            ack_timeout: 30_000,
+            max_redeliveries: None,
+        }
+    }
+}
+impl RedeliveryPolicy {
+    /// Set the maximum amount of redeliveries.
+    #[must_use]
+    pub fn with_max_redeliveries(mut self, max_redeliveries: u8) -> Self {
+        self.max_redeliveries = Some(max_redeliveries);
+        self
+    }
+
+    /// Check if an event sent at a Unix timestamp in milliseconds, and already
+    /// redelivered `redeliveries` times, should be redelivered now.
+    #[must_use]
+    pub fn should_redeliver(&self, sent_at: u64, redeliveries: u8, now: u64) -> bool {
+        now.saturating_sub(sent_at) >= u64::from(self.ack_timeout)
+            && self.max_redeliveries.is_none_or(|max| redeliveries < max)
+    }
+}
+
+/// The events sent to a connection that have not been acknowledged yet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct WebsocketPendingEvents {
+    /// The unacknowledged events, oldest first.
+    /// This may be fewer than `total` if the list was truncated.
+    pub events: Vec<crate::events::EventEnvelope>,
+
+    /// The total amount of unacknowledged events.
+    pub total: u64,
+}
+
+/// The encoding used for websocket frames.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum WebsocketEncoding {
+    /// JSON encoded text frames, used by default.
+    #[default]
+    Json,
+
+    /// `MessagePack` encoded binary frames.
+    MessagePack,
+}
+impl core::fmt::Display for WebsocketEncoding {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            WebsocketEncoding::Json => "Json",
+            WebsocketEncoding::MessagePack => "MessagePack",
+        })
+    }
+}
+
+/// Application level keepalive timings, in seconds.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct KeepaliveConfig {
+    /// How often a `Ping` should be sent.
+    pub interval: u32,
+
+    /// How long to wait for a `Pong` before the connection is considered dead.
+    pub timeout: u32,
+}
+impl KeepaliveConfig {
+    /// Combine two configs, using the more aggressive value of each timing.
+    #[must_use]
+    pub fn min(self, other: KeepaliveConfig) -> KeepaliveConfig {
+        KeepaliveConfig {
+            interval: self.interval.min(other.interval),
+            timeout: self.timeout.min(other.timeout),
+        }
+    }
+}
+
+/// Protocol version and feature flags exchanged when a connection is opened.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct WebsocketHello {
+    /// The protocol version spoken by the sender.
+    pub version: u16,
+
+    /// Optional protocol features supported by the sender.
+    #[serde(default)]
+    pub features: Vec<String>,
+
+    /// The channel the client wants to join, or the channel granted by the server.
+    /// Clients only receive events for their own channel. None uses the default channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+
+    /// The keepalive timings requested by the client, or the timings chosen by the
+    /// server. None uses the server default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keepalive: Option<KeepaliveConfig>,
+
+    /// The id assigned to this connection by the server, only set in the `HelloAck`.
+    /// This matches the `origin_client_id` of outgoing message events it sends.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<u64>,
+}
+impl Default for WebsocketHello {
+    fn default() -> Self {
+        Self {
+            version: PROTOCOL_VERSION,
+            features: Vec::new(),
+            channel: None,
+            keepalive: None,
+            client_id: None,
+        }
+    }
+}
+impl WebsocketHello {
+    /// Add a supported feature flag.
+    #[must_use]
+    pub fn with_feature(mut self, feature: impl Into<String>) -> Self {
+        self.features.push(feature.into());
+        self
+    }
+
+    /// Set the channel to join.
+    #[must_use]
+    pub fn with_channel(mut self, channel: impl Into<String>) -> Self {
+        self.channel = Some(channel.into());
+        self
+    }
+
+    /// Set the requested keepalive timings.
+    #[must_use]
+    pub fn with_keepalive(mut self, keepalive: KeepaliveConfig) -> Self {
+        self.keepalive = Some(keepalive);
+        self
+    }
+
+    /// Check if a protocol version can be spoken by this crate.
+    #[must_use]
+    pub const fn is_compatible(version: u16) -> bool {
+        version >= MIN_PROTOCOL_VERSION
+    }
+
+    /// Check if a feature flag is supported.
+    #[must_use]
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f == feature)
+    }
+
+    /// Negotiate with a peer hello, returning the lowest common version and the
+    /// features supported by both sides. The channel is taken from whichever side
+    /// specified one, preferring this side, and the keepalive uses the most aggressive
+    /// timings requested by either side. Returns None if the peer is incompatible.
+    #[must_use]
+    pub fn negotiate(&self, peer: &WebsocketHello) -> Option<WebsocketHello> {
+        let version = self.version.min(peer.version);
+        if !Self::is_compatible(version) {
+            return None;
+        }
+        Some(WebsocketHello {
+            version,
+            features: self
+                .features
+                .iter()
+                .filter(|feature| peer.has_feature(feature))
+                .cloned()
+                .collect(),
+            channel: self.channel.clone().or_else(|| peer.channel.clone()),
+            keepalive: match (self.keepalive, peer.keepalive) {
+                (Some(ours), Some(theirs)) => Some(ours.min(theirs)),
+                (ours, theirs) => ours.or(theirs),
+            },
+            client_id: self.client_id.or(peer.client_id),
+        })
+    }
+}
+
+/// The outcome of a `Resume` command.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all_fields = "camelCase"))]
+#[serde(tag = "status")]
+#[non_exhaustive]
+pub enum WebsocketResumeResult {
+    /// All frames after the requested sequence have been replayed.
+    Replayed {
+        /// The amount of frames that were replayed.
+        count: u64,
+    },
+
+    /// The requested sequence is no longer buffered by the server, so frames
+    /// have been missed and the client should re-synchronise over HTTP.
+    TooOld {
+        /// The oldest sequence number the server can still replay from.
+        oldest_sequence: u64,
+    },
+}
+
+/// Statistics for a single websocket connection.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct WebsocketConnectionStats {
+    /// Total amount of events sent to this connection.
+    pub events_sent: u64,
+
+    /// Total amount of events dropped, eg: because the send queue was full.
+    pub events_dropped: u64,
+
+    /// The amount of messages currently waiting in the send queue.
+    pub queue_depth: u32,
+
+    /// Unix timestamp when the connection was opened.
+    pub connected_since: u32,
+
+    /// The event kinds the connection is currently subscribed to.
+    /// A legacy integer bitmask is also accepted when deserializing.
+    pub subscription: crate::events::EventKindSet,
+}
+
+/// Capabilities advertised by a client, allowing the server to tailor what
+/// and how it sends to each connection.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct ClientCapabilities {
+    /// Can the client decode `MessagePack` binary frames?
+    #[serde(default)]
+    pub binary_frames: bool,
+
+    /// Can the client handle `Batch` messages?
+    #[serde(default)]
+    pub batching: bool,
+
+    /// The largest frame in bytes the client will accept, or None for no limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_frame_size: Option<u32>,
+
+    /// Does the client want to receive GNSS position reports?
+    #[serde(default)]
+    pub gnss: bool,
+}
+impl ClientCapabilities {
+    /// Set binary frame support.
+    #[must_use]
+    pub fn with_binary_frames(mut self, binary_frames: bool) -> Self {
+        self.binary_frames = binary_frames;
+        self
+    }
+
+    /// Set batch message support.
+    #[must_use]
+    pub fn with_batching(mut self, batching: bool) -> Self {
+        self.batching = batching;
+        self
+    }
+
+    /// Set the maximum accepted frame size in bytes.
+    #[must_use]
+    pub fn with_max_frame_size(mut self, max_frame_size: u32) -> Self {
+        self.max_frame_size = Some(max_frame_size);
+        self
+    }
+
+    /// Set whether GNSS position reports are wanted.
+    #[must_use]
+    pub fn with_gnss(mut self, gnss: bool) -> Self {
+        self.gnss = gnss;
+        self
+    }
+}
+
+/// A delivery report that has not yet been acknowledged by the client.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct WebsocketPendingDeliveryReport {
+    /// The target `message_id` this delivery report applies to.
+    pub message_id: i64,
+
+    /// The received delivery report.
+    pub report: crate::sms::SmsPartialDeliveryReport,
+}
+
+/// The current server state, sent to a client immediately after subscribing.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct WebsocketSnapshot {
+    /// The current modem status.
+    pub modem_status: crate::modem::ModemStatusUpdateState,
+
+    /// The current device info, if it could be read from the modem.
+    #[cfg(feature = "http")]
+    #[serde(default)]
+    pub device_info: Option<crate::http::HttpSmsDeviceInfoResponse>,
+
+    /// The last received GNSS position report.
+    #[cfg(feature = "gnss")]
+    #[serde(default)]
+    pub last_position: Option<crate::gnss::TimedPosition>,
+
+    /// Delivery reports that have not yet been acknowledged.
+    #[serde(default)]
+    pub pending_delivery_reports: Vec<WebsocketPendingDeliveryReport>,
+}
+
+/// Exponential backoff policy used by clients when reconnecting.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct ReconnectPolicy {
+    /// The delay before the first reconnection attempt, in milliseconds.
+    pub initial_delay: u64,
+
+    /// The maximum delay between attempts, in milliseconds.
+    pub max_delay: u64,
+
+    /// The factor the delay is multiplied by after each attempt.
+    pub multiplier: f64,
+
+    /// The fraction (0.0 - 1.0) of each delay that is randomised, to avoid
+    /// every client reconnecting at the same time after a server restart.
+    pub jitter: f64,
+
+    /// The maximum amount of attempts, or None to retry forever.
+    pub max_attempts: Option<u32>,
+}
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: 1000,
+            max_delay: 60_000,
+            multiplier: 2.0,
+            jitter: 0.2,
+            max_attempts: None,
+        }
+    }
+}
+impl ReconnectPolicy {
+    /// Set the maximum amount of attempts.
+    #[must_use]
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Set the jitter fraction, clamped to 0.0 - 1.0.
+    #[must_use]
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Get the delay for a (zero-indexed) attempt, where `random` is a sample
+    /// in the range 0.0 - 1.0 used to apply jitter. Returns None if the maximum
+    /// amount of attempts has been reached.
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    pub fn delay_for_attempt(&self, attempt: u32, random: f64) -> Option<std::time::Duration> {
+        if self.max_attempts.is_some_and(|max| attempt >= max) {
+            return None;
+        }
+
+        let exponent = i32::try_from(attempt).unwrap_or(i32::MAX);
+        let base =
+            (self.initial_delay as f64 * self.multiplier.powi(exponent)).min(self.max_delay as f64);
+
+        // Remove up to `jitter` of the delay, so the max delay is never exceeded.
+        let jitter = self.jitter.clamp(0.0, 1.0) * random.clamp(0.0, 1.0);
+        let delay = base * (1.0 - jitter);
+        Some(std::time::Duration::from_millis(delay as u64))
+    }
+
+    /// Create an iterator of delays for each reconnection attempt, using `seed`
+    /// to generate jitter. The same seed always produces the same delays.
+    #[must_use]
+    pub fn delays(&self, seed: u64) -> ReconnectDelays {
+        ReconnectDelays {
+            policy: *self,
+            attempt: 0,
+            state: seed | 1,
+        }
+    }
+}
+
+/// Iterator of reconnection delays, created by `ReconnectPolicy::delays`.
+#[derive(Debug, Clone)]
+pub struct ReconnectDelays {
+    policy: ReconnectPolicy,
+    attempt: u32,
+    state: u64,
+}
+impl ReconnectDelays {
+    /// The amount of delays produced so far.
+    #[must_use]
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Get the next jitter sample in the range 0.0 - 1.0 (xorshift64).
+    #[allow(clippy::cast_precision_loss)]
+    fn next_random(&mut self) -> f64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        (self.state >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+impl Iterator for ReconnectDelays {
+    type Item = std::time::Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let random = self.next_random();
+        let delay = self.policy.delay_for_attempt(self.attempt, random)?;
+        self.attempt = self.attempt.saturating_add(1);
+        Some(delay)
+    }
+}
+
+/// The reason a websocket connection was closed.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum WebsocketCloseReason {
+    /// The connection was closed normally by either side.
+    Normal,
+
+    /// The server is shutting down or restarting.
+    ServerShutdown,
+
+    /// The server rejected the client's authentication.
+    AuthFailed,
+
+    /// The peer sent a frame that could not be understood.
+    ProtocolError,
+
+    /// The peer stopped responding to keepalive pings.
+    Timeout,
+
+    /// The underlying network connection was lost or could not be established.
+    NetworkError(String),
+}
+impl std::fmt::Display for WebsocketCloseReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebsocketCloseReason::Normal => write!(f, "closed"),
+            WebsocketCloseReason::ServerShutdown => write!(f, "server shutdown"),
+            WebsocketCloseReason::AuthFailed => write!(f, "auth failed"),
+            WebsocketCloseReason::ProtocolError => write!(f, "protocol error"),
+            WebsocketCloseReason::Timeout => write!(f, "timed out"),
+            WebsocketCloseReason::NetworkError(error) => write!(f, "network error: {error}"),
+        }
+    }
+}
+
+/// Server reply to a `LatencyProbe`, with timestamps in Unix milliseconds.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct LatencyReport {
+    /// The nonce from the `LatencyProbe` being replied to.
+    pub nonce: u64,
+
+    /// Client clock time when the probe was sent.
+    pub client_sent_at: u64,
+
+    /// Server clock time when the probe was received.
+    pub server_received_at: u64,
+
+    /// Server clock time when this report was sent.
+    pub server_sent_at: u64,
+}
+impl LatencyReport {
+    /// Get the network round trip time in milliseconds, excluding server processing time,
+    /// where `client_received_at` is the client clock time the report was received.
+    /// Saturates at the i64 range.
+    #[must_use]
+    pub fn round_trip(&self, client_received_at: u64) -> i64 {
+        let total = i128::from(client_received_at) - i128::from(self.client_sent_at);
+        let processing = i128::from(self.server_sent_at) - i128::from(self.server_received_at);
+        saturating_i64(total - processing)
+    }
+
+    /// Get the estimated offset in milliseconds of the server clock relative to the
+    /// client clock, where `client_received_at` is the client clock time the report was received.
+    /// Saturates at the i64 range.
+    #[must_use]
+    pub fn clock_offset(&self, client_received_at: u64) -> i64 {
+        let outbound = i128::from(self.server_received_at) - i128::from(self.client_sent_at);
+        let inbound = i128::from(self.server_sent_at) - i128::from(client_received_at);
+        saturating_i64(i128::midpoint(outbound, inbound))
+    }
+}
+
+/// Convert to an i64, saturating at its range.
+fn saturating_i64(value: i128) -> i64 {
+    i64::try_from(value).unwrap_or(if value < 0 { i64::MIN } else { i64::MAX })
+}
+
+/// Commands that can be sent by a client to the server.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all_fields = "camelCase"))]
+#[serde(tag = "type", content = "data")]
+#[non_exhaustive]
+pub enum WebsocketCommand {
+    /// Send an SMS message.
+    #[serde(rename = "send_sms")]
+    SendSms(crate::sms::SmsOutgoingMessage),
+
+    /// Application level keepalive, the server replies with a `Pong`.
+    #[serde(rename = "ping")]
+    Ping {
+        /// A client chosen value, echoed back in the `Pong`.
+        nonce: u64,
+
+        /// Unix timestamp in milliseconds when the ping was sent.
+        sent_at: u64,
+    },
+
+    /// Replace the connection's event subscription, the server replies with a `SubscriptionAck`.
+    #[serde(rename = "set_subscription")]
+    SetSubscription(WebsocketSubscription),
+
+    /// Sent when a connection is opened, the server replies with a `HelloAck`.
+    #[serde(rename = "hello")]
+    Hello(WebsocketHello),
+
+    /// Replay all frames sent after a sequence number, used after reconnecting.
+    /// The server replies with a `ResumeResult` once the replay is complete.
+    #[serde(rename = "resume")]
+    Resume {
+        /// The last sequence number received by the client.
+        last_sequence: u64,
+    },
+
+    /// Switch the encoding used for all following frames in both directions.
+    /// The server replies with a `CommandResult` already using the new encoding.
+    #[serde(rename = "set_encoding")]
+    SetEncoding(WebsocketEncoding),
+
+    /// Request statistics for this connection, the server replies with `ConnectionStats`.
+    #[serde(rename = "get_stats")]
+    GetStats,
+
+    /// Advertise the client capabilities, sent after the `Hello` handshake.
+    #[serde(rename = "capabilities")]
+    Capabilities(ClientCapabilities),
+
+    /// Measure latency and clock skew, the server replies with a `LatencyReport`.
+    #[serde(rename = "latency_probe")]
+    LatencyProbe {
+        /// A client chosen value, echoed back in the `LatencyReport`.
+        nonce: u64,
+
+        /// Client clock time in Unix milliseconds when the probe was sent.
+        client_sent_at: u64,
+    },
+
+    /// Acknowledge an event, so it is not redelivered.
+    #[serde(rename = "event_ack")]
+    EventAck {
+        /// The `event_id` of the acknowledged event.
+        event_id: crate::events::Uuid,
+    },
+
+    /// Request the unacknowledged events, the server replies with `PendingEvents`.
+    #[serde(rename = "get_pending")]
+    GetPending,
+}
+
+/// Messages that can be sent by the server to a client.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "camel-case", serde(rename_all_fields = "camelCase"))]
+#[serde(tag = "type", content = "data", remote = "Self")]
+#[non_exhaustive]
+pub enum WebsocketMessage {
+    /// The result of a previously sent command, correlated by the frame id.
+    #[serde(rename = "command_result")]
+    CommandResult {
+        /// Whether the command was successfully executed.
+        success: bool,
+
+        /// The reason the command failed, if unsuccessful.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+
+    /// The reply to a client `Ping`, echoing its values back.
+    /// The round trip time can be measured by comparing `sent_at` to the current time.
+    #[serde(rename = "pong")]
+    Pong {
+        /// The nonce from the `Ping` being replied to.
+        nonce: u64,
+
+        /// Unix timestamp in milliseconds when the `Ping` was sent.
+        sent_at: u64,
+    },
+
+    /// The reply to a `LatencyProbe`.
+    #[serde(rename = "latency_report")]
+    LatencyReport(LatencyReport),
+
+    /// The subscription that was actually granted after a `SetSubscription`.
+    /// This may contain fewer events than requested, eg: if GNSS is disabled.
+    #[serde(rename = "subscription_ack")]
+    SubscriptionAck(WebsocketSubscription),
+
+    /// The negotiated protocol version and features in reply to a `Hello`.
+    #[serde(rename = "hello_ack")]
+    HelloAck(WebsocketHello),
+
+    /// The outcome of a `Resume` command.
+    #[serde(rename = "resume_result")]
+    ResumeResult(WebsocketResumeResult),
+
+    /// The result of a `SendSms` command, containing the assigned message id and reference.
+    #[serde(rename = "send_result")]
+    SendResult {
+        /// The frame id of the `SendSms` command this is the result of.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        command_id: Option<u64>,
+
+        /// The send response, or the reason the message could not be sent.
+        result: Result<crate::sms::SmsSendResponse, crate::sms::ApiError>,
+    },
+
+    /// A command failure or server side problem, reported without closing the connection.
+    #[serde(rename = "error")]
+    Error {
+        /// The category of error.
+        code: crate::sms::ApiErrorCode,
+
+        /// A human readable description of the error.
+        message: String,
+
+        /// The frame id of the command that caused this error, if any.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        related_command_id: Option<u64>,
+    },
+
+    /// The current server state, sent immediately after subscribing.
+    #[serde(rename = "snapshot")]
+    Snapshot(WebsocketSnapshot),
+
+    /// Statistics for this connection, in reply to a `GetStats` command.
+    #[serde(rename = "connection_stats")]
+    ConnectionStats(WebsocketConnectionStats),
+
+    /// Sent before a planned server restart or shutdown, allowing clients to
+    /// distinguish it from a crash and schedule their reconnection.
+    #[serde(rename = "server_shutdown")]
+    ServerShutdown {
+        /// A human readable reason for the shutdown.
+        reason: String,
+
+        /// How many seconds the client should wait before reconnecting.
+        /// None if the server is not expected to come back online.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reconnect_after: Option<u32>,
+    },
+
+    /// Multiple messages coalesced into a single frame, eg: all parts of a
+    /// multipart message arriving at once. These should be handled in order.
+    #[serde(rename = "batch")]
+    Batch(Vec<WebsocketMessage>),
+
+    /// The unacknowledged events for this connection, in reply to `GetPending`.
+    #[serde(rename = "pending_events")]
+    PendingEvents(WebsocketPendingEvents),
+
+    /// An event with its id, sequence and emit time, for clients that
+    /// need ordering and deduplication.
+    #[serde(rename = "event_envelope")]
+    EventEnvelope(crate::events::EventEnvelope),
+
+    /// WebSocket connection status update (client-side only).
+    /// This message is generated locally when there is a connection or disconnection.
+    WebsocketConnectionUpdate {
+        /// Connection status: true = connected, false = disconnected
+        connected: bool,
+
+        /// If connection is false, will the client attempt to automatically reconnect?
+        reconnect: bool,
+
+        /// The reason the connection was closed, if disconnected.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<WebsocketCloseReason>,
+
+        /// The reconnection attempt number, starting from 1.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        attempt: Option<u32>,
+
+        /// The delay in milliseconds until the next reconnection attempt.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        retry_after: Option<u64>,
+    },
+
+    /// An event broadcast by the server.
+    #[cfg_attr(feature = "schemars", schemars(untagged))]
+    #[cfg_attr(feature = "ts-rs", ts(untagged))]
+    Event(crate::events::Event),
+}
+
+impl Serialize for WebsocketMessage {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            WebsocketMessage::Event(event) => event.serialize(serializer),
+            _ => Self::serialize(self, serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for WebsocketMessage {
+    /// Read the message type first, so transport messages use the tagged representation
+    /// with precise errors, and only other types are read as an `Event`.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let human_readable = deserializer.is_human_readable();
+        deserializer.deserialize_struct(
+            "WebsocketMessage",
+            &["type", "data"],
+            WebsocketMessageVisitor { human_readable },
+        )
+    }
+}
+
+struct WebsocketMessageVisitor {
+    human_readable: bool,
+}
+impl<'de> de::Visitor<'de> for WebsocketMessageVisitor {
+    type Value = WebsocketMessage;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("a websocket message with a type and data")
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<WebsocketMessage, A::Error> {
+        let tagged = crate::events::TaggedMap::read(map, self.human_readable)?;
+        if is_transport_type(&tagged.kind) {
+            WebsocketMessage::deserialize(tagged.into_deserializer())
+        } else {
+            <crate::events::Event as Deserialize>::deserialize(tagged.into_deserializer())
+                .map(WebsocketMessage::Event)
+        }
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<WebsocketMessage, A::Error> {
+        let kind: String = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let replay = de::value::SeqAccessDeserializer::new(crate::events::ReplayType::new(
+            kind.clone(),
+            seq,
+        ));
+        if is_transport_type(&kind) {
+            WebsocketMessage::deserialize(replay)
+        } else {
+            <crate::events::Event as Deserialize>::deserialize(replay).map(WebsocketMessage::Event)
+        }
+    }
+}
+
+/// Check if a message type is a transport message, rather than an event.
+/// Only the derived representation knows every variant's type, so it's asked to read just
+/// the type, and a transport type is one it doesn't reject.
+fn is_transport_type(kind: &str) -> bool {
+    // The `Event` variant is serialized as the event itself, so its name is never a type.
+    if kind == "Event" {
+        return false;
+    }
+
+    let mut probe = TypeProbe {
+        kind: Some(kind),
+        rejected: false,
+    };
+    // Only the rejection matters, the rest fails on the missing data.
+    let _ = WebsocketMessage::deserialize(de::value::MapAccessDeserializer::new(&mut probe));
+    !probe.rejected
+}
+
+/// A map holding only a type, which records whether the type was rejected.
+struct TypeProbe<'a> {
+    kind: Option<&'a str>,
+    rejected: bool,
+}
+impl<'de> de::MapAccess<'de> for TypeProbe<'_> {
+    type Error = de::value::Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        if self.kind.is_none() {
+            return Ok(None);
+        }
+        seed.deserialize(de::value::StrDeserializer::new("type"))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let kind = self.kind.take().unwrap_or_default();
+        let result = seed.deserialize(de::value::StrDeserializer::new(kind));
+        self.rejected = result.is_err();
+        result
+    }
+}
+impl WebsocketMessage {
+    /// Get the `EventKind` of this message, if it is an event or connection update.
+    #[must_use]
+    pub fn kind(&self) -> Option<crate::events::EventKind> {
+        match self {
+            WebsocketMessage::Event(event) => Some(crate::events::EventKind::from(event)),
+            WebsocketMessage::EventEnvelope(envelope) => Some(envelope.kind()),
+            WebsocketMessage::WebsocketConnectionUpdate { .. } => {
+                Some(crate::events::EventKind::WebsocketConnectionUpdate)
+            }
+            _ => None,
+        }
+    }
+}
+impl From<crate::events::Event> for WebsocketMessage {
+    fn from(value: crate::events::Event) -> Self {
+        WebsocketMessage::Event(value)
+    }
+}
+impl TryFrom<WebsocketMessage> for crate::events::Event {
+    type Error = WebsocketMessage;
+
+    /// Unwrap the event from a message, returning the message back if it's transport only.
+    fn try_from(value: WebsocketMessage) -> Result<Self, Self::Error> {
+        match value {
+            WebsocketMessage::Event(event) => Ok(event),
+            WebsocketMessage::EventEnvelope(envelope) => Ok(envelope.event),
+            other => Err(other),
+        }
+    }
+}